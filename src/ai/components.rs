@@ -1,9 +1,40 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, reflect::TypePath};
 use big_brain::prelude::*;
+use serde::Deserialize;
+
+use crate::ron_asset::loader::RonAsset;
 
 #[derive(Debug, Clone, Component, ScorerBuilder)]
 pub struct Hostile;
 
+/// Scores when the actor's health drops below the threshold, as a fraction of its maximum.
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct LowHealth {
+    pub threshold: f32,
+}
+
+/// Scores when the actor strayed further than the radius from the nearest other enemy.
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct Isolated {
+    pub radius: f32,
+}
+
+/// Scores when an ore chunk is within range.
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct OreInSight {
+    pub range: f32,
+}
+
+/// Scores when a station module is within range.
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct StationInSight {
+    pub range: f32,
+}
+
+/// Scores when a carrier has room for another drone and is ready to launch it.
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct HangarReady;
+
 #[derive(Component, Debug)]
 pub struct Hostility {
     pub per_second: f32,
@@ -30,6 +61,42 @@ pub struct MoveTowardsPlayer {
     pub speed: f32,
 }
 
+/// Circles the player at the radius for a number of seconds.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct Orbit {
+    pub radius: f32,
+    pub duration: f32,
+}
+
+/// Runs from the player until the distance between them is reached.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct Flee {
+    pub distance: f32,
+}
+
+/// Closes in on the nearest other enemy until within the radius.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct Regroup {
+    pub radius: f32,
+}
+
+/// Flies to the nearest ore chunk and makes off with it once within reach.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct StealOre {
+    pub reach: f32,
+}
+
+/// Rams the nearest station module, damaging it and destroying the bomber.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct BombModule {
+    pub reach: f32,
+    pub damage: f32,
+}
+
+/// Launches a drone from the carrier's hangar.
+#[derive(Clone, Component, Debug, ActionBuilder)]
+pub struct LaunchDrone;
+
 #[derive(Component, Debug, Copy, Clone)]
 pub struct Position {
     pub position: Vec2,
@@ -37,3 +104,148 @@ pub struct Position {
 
 #[derive(Component)]
 pub struct Enemy;
+
+/// The id of the archetype an enemy was spawned from.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Archetype(pub String);
+
+/// How hard and how fast an enemy can fly.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnemyDrive {
+    pub thrust: f32,
+    pub max_speed: f32,
+}
+
+impl EnemyDrive {
+    /// Thrust vector bringing the velocity closer to the desired one,
+    /// at full thrust once they're `max_speed` apart.
+    pub fn steer(&self, velocity: Vec2, desired: Vec2) -> Vec2 {
+        ((desired - velocity) / self.max_speed).clamp_length_max(1.0) * self.thrust
+    }
+}
+
+/// Drones launched by a carrier, and the time until it can launch the next one.
+#[derive(Component, Debug)]
+pub struct Carrier {
+    pub drone: String,
+    pub max_drones: usize,
+    pub drones: Vec<Entity>,
+    pub cooldown: Timer,
+}
+
+impl Carrier {
+    pub fn is_ready(&self) -> bool {
+        self.cooldown.finished() && self.drones.len() < self.max_drones
+    }
+}
+
+/// The behaviour tree an archetype thinks with.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum EnemyBehaviour {
+    /// Strafes around the player, firing in between.
+    Skirmisher { orbit_radius: f32 },
+    /// Steals ore chunks, fighting only when there are none around.
+    Raider { steal_range: f32 },
+    /// Flies into station modules.
+    Bomber { damage: f32 },
+    /// Keeps its distance and launches drones.
+    Carrier {
+        drone: String,
+        max_drones: usize,
+        launch_interval: f32,
+        orbit_radius: f32,
+    },
+    /// Closes in and attacks, like the plain enemy.
+    Drone,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EnemyArchetype {
+    pub id: String,
+    pub name: String,
+    pub behaviour: EnemyBehaviour,
+    /// Radius of the hull in pixels.
+    pub radius: f32,
+    /// Hull color as linear rgb.
+    pub color: (f32, f32, f32),
+    pub health: f32,
+    pub thrust: f32,
+    pub max_speed: f32,
+    /// Fraction of its health below which the enemy flees, if it ever does.
+    #[serde(default)]
+    pub flee_below: Option<f32>,
+    #[serde(default = "default_hostility_per_second")]
+    pub hostility_per_second: f32,
+    /// Id of the weapon definition the enemy fires, unarmed enemies only ram or bomb.
+    #[serde(default)]
+    pub weapon: Option<String>,
+    /// Share of a wave's budget the archetype takes up.
+    pub cost: f32,
+    /// First wave the archetype shows up in.
+    #[serde(default = "default_min_wave")]
+    pub min_wave: u32,
+    /// Most of the archetype a single wave brings along.
+    #[serde(default)]
+    pub max_per_wave: Option<u32>,
+}
+
+fn default_hostility_per_second() -> f32 {
+    2.0
+}
+
+fn default_min_wave() -> u32 {
+    1
+}
+
+/// The contents of an `*.enemies.ron` asset file.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct EnemyArchetypes {
+    pub archetypes: Vec<EnemyArchetype>,
+}
+
+impl RonAsset for EnemyArchetypes {
+    const EXTENSIONS: &'static [&'static str] = &["enemies.ron"];
+}
+
+#[test]
+fn test_spawnable_archetypes_leave_out_drones() {
+    use super::resources::EnemyArchetypeRegistry;
+
+    let archetypes: EnemyArchetypes = ron::de::from_str(
+        r#"(archetypes: [
+            (id: "carrier", name: "Carrier", behaviour: Carrier(drone: "drone", max_drones: 2, launch_interval: 5.0, orbit_radius: 500.0),
+                radius: 20.0, color: (1.0, 0.0, 0.0), health: 100.0, thrust: 100.0, max_speed: 50.0, flee_below: Some(0.2), cost: 5.0),
+            (id: "drone", name: "Drone", behaviour: Drone, radius: 5.0, color: (1.0, 0.0, 0.0), health: 10.0, thrust: 10.0, max_speed: 100.0, cost: 0.5),
+        ])"#,
+    )
+    .unwrap();
+
+    let registry = EnemyArchetypeRegistry::from_archetypes(&archetypes);
+    let spawnable: Vec<&str> = registry
+        .spawnable()
+        .map(|archetype| archetype.id.as_str())
+        .collect();
+    assert_eq!(spawnable, vec!["carrier"]);
+
+    let drone = registry.get("drone").unwrap();
+    assert_eq!(drone.flee_below, None);
+    assert_eq!(drone.hostility_per_second, 2.0);
+    assert_eq!(drone.min_wave, 1);
+    assert_eq!(drone.max_per_wave, None);
+}
+
+#[test]
+fn test_steering_is_limited_to_full_thrust() {
+    let drive = EnemyDrive {
+        thrust: 10.0,
+        max_speed: 100.0,
+    };
+
+    // Halfway to the desired velocity takes half the thrust.
+    let half = drive.steer(Vec2::ZERO, Vec2::new(50.0, 0.0));
+    assert_eq!(half, Vec2::new(5.0, 0.0));
+
+    // Turning around takes full thrust, and no more.
+    let reverse = drive.steer(Vec2::new(100.0, 0.0), Vec2::new(-100.0, 0.0));
+    assert_eq!(reverse, Vec2::new(-10.0, 0.0));
+}
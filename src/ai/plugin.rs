@@ -1,15 +1,22 @@
 use bevy::{
-    app::{App, Plugin, PreUpdate, Update},
+    app::{App, Plugin, PreStartup, PreUpdate, Update},
+    asset::AssetApp,
     ecs::schedule::IntoSystemConfigs,
-    time::{Timer, TimerMode},
 };
 use big_brain::{BigBrainPlugin, BigBrainSet};
 
+use crate::ron_asset::{loader::RonAssetLoader, systems::rebuild_from_asset};
+
 use super::{
-    resources::EnemySpawnTimer,
+    components::EnemyArchetypes,
+    resources::EnemyArchetypeRegistry,
     systems::{
-        attack_action_system, despawn_dead_enemies, hostility_scorer_system, hostility_system,
-        move_towards_player_action_system, spawn_enemies,
+        attack_action_system, bomb_module_action_system, despawn_killed_enemies,
+        flee_action_system, hangar_ready_scorer_system, hostility_scorer_system, hostility_system,
+        isolated_scorer_system, launch_drone_action_system, load_enemy_archetypes,
+        low_health_scorer_system, move_towards_player_action_system, orbit_action_system,
+        ore_in_sight_scorer_system, regroup_action_system, station_in_sight_scorer_system,
+        steal_ore_action_system, update_carriers,
     },
 };
 
@@ -17,23 +24,44 @@ pub struct AiPlugin;
 
 impl Plugin for AiPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<EnemySpawnTimer>()
-            .insert_resource(EnemySpawnTimer {
-                timer: Timer::from_seconds(100.0, TimerMode::Repeating),
-            })
+        app.init_asset::<EnemyArchetypes>()
+            .init_asset_loader::<RonAssetLoader<EnemyArchetypes>>()
+            .init_resource::<EnemyArchetypeRegistry>()
             .add_plugins(BigBrainPlugin::new(PreUpdate))
+            .add_systems(PreStartup, load_enemy_archetypes)
+            .add_systems(
+                PreUpdate,
+                rebuild_from_asset::<EnemyArchetypes, EnemyArchetypeRegistry>,
+            )
             .add_systems(
                 Update,
-                (hostility_system, spawn_enemies, despawn_dead_enemies),
+                (hostility_system, despawn_killed_enemies, update_carriers),
             )
             .add_systems(
                 PreUpdate,
                 (
                     attack_action_system,
-                    hostility_scorer_system,
                     move_towards_player_action_system,
+                    orbit_action_system,
+                    flee_action_system,
+                    regroup_action_system,
+                    steal_ore_action_system,
+                    bomb_module_action_system,
+                    launch_drone_action_system,
                 )
                     .in_set(BigBrainSet::Actions),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    hostility_scorer_system,
+                    low_health_scorer_system,
+                    isolated_scorer_system,
+                    ore_in_sight_scorer_system,
+                    station_in_sight_scorer_system,
+                    hangar_ready_scorer_system,
+                )
+                    .in_set(BigBrainSet::Scorers),
             );
     }
 }
@@ -1,6 +1,48 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
 
-#[derive(Resource, Default)]
-pub struct EnemySpawnTimer {
-    pub timer: Timer,
+use crate::ron_asset::systems::FromAsset;
+
+use super::components::{EnemyArchetype, EnemyArchetypes, EnemyBehaviour};
+
+pub const ENEMY_ARCHETYPES_PATH: &str = "data/core.enemies.ron";
+
+#[derive(Resource)]
+pub struct EnemyArchetypesHandle(pub Handle<EnemyArchetypes>);
+
+/// Lookup table of every enemy archetype, built from the enemy archetypes asset.
+#[derive(Resource, Default, Debug)]
+pub struct EnemyArchetypeRegistry {
+    archetypes: HashMap<String, EnemyArchetype>,
+}
+
+impl FromAsset<EnemyArchetypes> for EnemyArchetypeRegistry {
+    fn from_asset(archetypes: &EnemyArchetypes) -> Self {
+        Self::from_archetypes(archetypes)
+    }
+}
+
+impl EnemyArchetypeRegistry {
+    pub fn from_archetypes(archetypes: &EnemyArchetypes) -> Self {
+        Self {
+            archetypes: archetypes
+                .archetypes
+                .iter()
+                .map(|archetype| (archetype.id.clone(), archetype.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&EnemyArchetype> {
+        self.archetypes.get(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &EnemyArchetype> {
+        self.archetypes.values()
+    }
+
+    /// Archetypes that show up on their own, leaving out drones which are launched by carriers.
+    pub fn spawnable(&self) -> impl Iterator<Item = &EnemyArchetype> {
+        self.iter()
+            .filter(|archetype| archetype.behaviour != EnemyBehaviour::Drone)
+    }
 }
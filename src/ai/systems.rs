@@ -1,106 +1,251 @@
-use std::{borrow::BorrowMut, f32::consts::PI};
-
 use avian2d::prelude::*;
-use bevy::{color::palettes::css::RED, prelude::*};
+use bevy::prelude::*;
 use big_brain::{
     actions::{ActionState, Steps},
     pickers::FirstToScore,
     scorers::Score,
-    thinker::{ActionSpan, Actor, ScorerSpan, Thinker},
+    thinker::{ActionSpan, Actor, ScorerSpan, Thinker, ThinkerBuilder},
 };
-use rand::Rng;
 
 use crate::{
-    health::components::Health,
+    collectible::components::Collectible,
+    health::{
+        components::{DamageType, Health},
+        events::{DamageEvent, KilledEvent},
+    },
     player::components::Player,
-    projectile::events::FireProjectileEvent,
+    projectile::{
+        components::{Faction, Weapon},
+        events::FireProjectileEvent,
+    },
     rcs::{components::RCSBooster, events::RCSThrustVectorEvent},
+    space_station::modules::components::SpaceStationModuleType,
 };
 
 use super::{
-    components::{Attack, Enemy, Hostile, Hostility, MoveTowardsPlayer},
-    resources::EnemySpawnTimer,
+    components::{
+        Archetype, Attack, BombModule, Carrier, Enemy, EnemyArchetype, EnemyBehaviour, EnemyDrive,
+        Flee, HangarReady, Hostile, Hostility, Isolated, LaunchDrone, LowHealth, MoveTowardsPlayer,
+        Orbit, OreInSight, Regroup, StationInSight, StealOre,
+    },
+    resources::{EnemyArchetypeRegistry, EnemyArchetypesHandle, ENEMY_ARCHETYPES_PATH},
 };
 
-pub fn spawn_enemies(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut spawn_time: ResMut<EnemySpawnTimer>,
-    keys: Res<ButtonInput<KeyCode>>,
-    meshes: ResMut<Assets<Mesh>>,
-    materials: ResMut<Assets<ColorMaterial>>,
-) {
-    spawn_time.timer.tick(time.delta());
+/// Distance enemies run to before they stop fleeing.
+const FLEE_DISTANCE: f32 = 1500.0;
+/// Skirmishers further than this from the nearest other enemy go back to it.
+const REGROUP_RADIUS: f32 = 300.0;
+/// Distance within which raiders grab ore chunks and bombers hit modules.
+const REACH: f32 = 30.0;
+/// Bombers go for modules within this distance.
+const BOMBER_SIGHT: f32 = 3000.0;
+/// Seconds skirmishers circle between shots.
+const STRAFE_DURATION: f32 = 2.0;
 
-    if spawn_time.timer.finished() || keys.just_pressed(KeyCode::F1) {
-        spawn_enemy(commands.borrow_mut(), meshes, materials);
-        spawn_time.timer.reset();
-    }
+pub fn load_enemy_archetypes(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EnemyArchetypesHandle(
+        asset_server.load(ENEMY_ARCHETYPES_PATH),
+    ));
 }
 
 pub fn spawn_enemy(
     cmd: &mut Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-) {
+    archetype: &EnemyArchetype,
+    position: Vec2,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> Entity {
+    let (red, green, blue) = archetype.color;
+
+    let mut enemy = cmd.spawn((
+        Mesh2d(meshes.add(Circle::new(archetype.radius))),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::linear_rgb(
+            red, green, blue,
+        )))),
+        Transform::from_translation(position.extend(0.0)),
+        RCSBooster::new(),
+        RigidBody::Dynamic,
+        Collider::circle(archetype.radius as f64),
+        ExternalForce::ZERO,
+        LinearVelocity::ZERO,
+    ));
+
+    enemy.insert((
+        Health::with_maximum(archetype.health),
+        Hostility::new(75.0, archetype.hostility_per_second),
+        EnemyDrive {
+            thrust: archetype.thrust,
+            max_speed: archetype.max_speed,
+        },
+        build_thinker(archetype),
+        Name::new(archetype.name.clone()),
+        Archetype(archetype.id.clone()),
+        Enemy,
+    ));
+
+    if let Some(weapon) = &archetype.weapon {
+        enemy.insert((Weapon::new(weapon.clone()), Faction::Enemy));
+    }
+
+    if let EnemyBehaviour::Carrier {
+        drone,
+        max_drones,
+        launch_interval,
+        ..
+    } = &archetype.behaviour
+    {
+        enemy.insert(Carrier {
+            drone: drone.clone(),
+            max_drones: *max_drones,
+            drones: Vec::new(),
+            cooldown: Timer::from_seconds(*launch_interval, TimerMode::Once),
+        });
+    }
+
+    enemy.id()
+}
+
+/// The behaviour tree of an archetype. Fleeing comes first for archetypes that flee at all.
+fn build_thinker(archetype: &EnemyArchetype) -> ThinkerBuilder {
     let move_towards_player_and_attack = Steps::build()
         .label("MoveTowardsPlayerAndAttack")
         .step(MoveTowardsPlayer {
-            speed: 10000000000.0,
+            speed: archetype.max_speed,
         })
         .step(Attack {
             until: 5.0,
             per_second: 1.0,
         });
 
-    let thinker = Thinker::build()
-        .label("Ai Thinker")
-        .picker(FirstToScore { threshold: 0.8 })
-        .when(Hostile, move_towards_player_and_attack);
-
-    let mut rng = rand::thread_rng();
+    let mut thinker = Thinker::build()
+        .label(format!("{} Thinker", archetype.name))
+        .picker(FirstToScore { threshold: 0.8 });
 
-    let rand = rng.gen::<f32>() * 2.0 * PI;
-    let random_dir = Vec2::new(f32::cos(rand), f32::sin(rand));
+    if let Some(threshold) = archetype.flee_below {
+        thinker = thinker.when(
+            LowHealth { threshold },
+            Flee {
+                distance: FLEE_DISTANCE,
+            },
+        );
+    }
 
-    cmd.spawn((
-        Mesh2d(meshes.add(Circle::default())),
-        MeshMaterial2d(materials.add(ColorMaterial::from_color(RED))),
-        Transform {
-            translation: (random_dir * 1000.0).extend(0.0),
-            scale: Vec2::new(10.0, 10.0).extend(1.0),
-            ..default()
-        },
-        RCSBooster::new(),
-        RigidBody::Dynamic,
-        Collider::circle(1.0),
-        Health::new(),
-        LinearVelocity::ZERO,
-        Hostility::new(75.0, 2.0),
-        thinker.clone(),
-        Name::new("Enemy"),
-        Enemy,
-    ));
+    match &archetype.behaviour {
+        EnemyBehaviour::Skirmisher { orbit_radius } => thinker
+            .when(
+                Isolated {
+                    radius: REGROUP_RADIUS,
+                },
+                Regroup {
+                    radius: REGROUP_RADIUS / 2.0,
+                },
+            )
+            .when(
+                Hostile,
+                Steps::build()
+                    .label("StrafeAndAttack")
+                    .step(MoveTowardsPlayer {
+                        speed: archetype.max_speed,
+                    })
+                    .step(Orbit {
+                        radius: *orbit_radius,
+                        duration: STRAFE_DURATION,
+                    })
+                    .step(Attack {
+                        until: 5.0,
+                        per_second: 1.0,
+                    }),
+            ),
+        EnemyBehaviour::Raider { steal_range } => thinker
+            .when(
+                OreInSight {
+                    range: *steal_range,
+                },
+                StealOre { reach: REACH },
+            )
+            .when(Hostile, move_towards_player_and_attack),
+        EnemyBehaviour::Bomber { damage } => thinker
+            .when(
+                StationInSight {
+                    range: BOMBER_SIGHT,
+                },
+                BombModule {
+                    reach: REACH,
+                    damage: *damage,
+                },
+            )
+            .when(Hostile, move_towards_player_and_attack),
+        EnemyBehaviour::Carrier { orbit_radius, .. } => {
+            thinker.when(HangarReady, LaunchDrone).when(
+                Hostile,
+                Orbit {
+                    radius: *orbit_radius,
+                    duration: STRAFE_DURATION,
+                },
+            )
+        }
+        EnemyBehaviour::Drone => thinker.when(Hostile, move_towards_player_and_attack),
+    }
 }
 
-pub fn despawn_dead_enemies(mut commands: Commands, enemies: Query<(Entity, &Enemy, &Health)>) {
-    for (entity, _, health) in enemies.iter() {
-        if health.current <= 0.0 {
-            commands.entity(entity).despawn_recursive();
+pub fn despawn_killed_enemies(
+    mut commands: Commands,
+    mut killed_events: EventReader<KilledEvent>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    for evt in killed_events.read() {
+        if enemies.contains(evt.entity) {
+            commands.entity(evt.entity).despawn_recursive();
         }
     }
 }
 
+/// Counts down carrier launch cooldowns and forgets drones that were destroyed.
+pub fn update_carriers(
+    mut carriers: Query<&mut Carrier>,
+    enemies: Query<(), With<Enemy>>,
+    time: Res<Time>,
+) {
+    for mut carrier in carriers.iter_mut() {
+        carrier.cooldown.tick(time.delta());
+        carrier.drones.retain(|drone| enemies.contains(*drone));
+    }
+}
+
+/// Thrusts the actor towards the desired velocity.
+fn steer(
+    commands: &mut Commands,
+    entity: Entity,
+    drive: &EnemyDrive,
+    velocity: &LinearVelocity,
+    desired: Vec2,
+) {
+    commands.trigger(RCSThrustVectorEvent {
+        entity,
+        thrust_vector: drive.steer(velocity.0.as_vec2(), desired),
+    });
+}
+
+/// The closest of the positions to the point, with its distance.
+fn nearest<T>(point: Vec2, candidates: impl Iterator<Item = (T, Vec2)>) -> Option<(T, Vec2, f32)> {
+    candidates
+        .map(|(item, position)| (item, position, point.distance(position)))
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+}
+
 // ACTIONS
 pub fn attack_action_system(
     time: Res<Time>,
     player_q: Query<&GlobalTransform, With<Player>>,
     positions: Query<&GlobalTransform, Without<Player>>,
-    velocities: Query<&LinearVelocity>,
     mut hostilities: Query<&mut Hostility>,
     mut query: Query<(&Actor, &mut ActionState, &Attack, &ActionSpan)>,
     mut fire_projectile_events: EventWriter<FireProjectileEvent>,
 ) {
+    let Ok(player_gt) = player_q.get_single() else {
+        return;
+    };
+
     for (Actor(actor), mut state, attack, span) in &mut query {
         // This sets up the tracing scope. Any `debug` calls here will be
         // spanned together in the output.
@@ -117,24 +262,16 @@ pub fn attack_action_system(
                     hostility.hostility -=
                         attack.per_second * (time.delta().as_micros() as f32 / 1_000_000.0);
 
-                    let player_gt = player_q.single();
                     let actor_gt = positions
                         .get(*actor)
                         .expect("actor does not have a global transform");
-                    let actor_lin_vel = velocities
-                        .get(*actor)
-                        .expect("actor does not have linear velocity!");
 
                     let dir_to_player = (player_gt.translation() - actor_gt.translation())
                         .truncate()
                         .normalize();
                     fire_projectile_events.send(FireProjectileEvent {
                         entity: *actor,
-                        projectile_trajectory: LinearVelocity(
-                            actor_lin_vel.0
-                                + (dir_to_player * 50.0 * crate::PIXELS_PER_METER as f32)
-                                    .as_dvec2(),
-                        ),
+                        direction: dir_to_player,
                     });
 
                     *state = ActionState::Success;
@@ -157,12 +294,15 @@ pub fn attack_action_system(
 pub const MAX_DISTANCE: f32 = 500.0;
 
 pub fn move_towards_player_action_system(
-    time: Res<Time>,
-    player_q: Query<(Entity, &GlobalTransform, &LinearVelocity), With<Player>>,
-    mut enemies: Query<(&mut GlobalTransform, &LinearVelocity), Without<Player>>,
+    mut commands: Commands,
+    player_q: Query<(&GlobalTransform, &LinearVelocity), With<Player>>,
+    enemies: Query<(&GlobalTransform, &LinearVelocity, &EnemyDrive), Without<Player>>,
     mut action_query: Query<(&Actor, &mut ActionState, &MoveTowardsPlayer, &ActionSpan)>,
-    mut thrust_events: EventWriter<RCSThrustVectorEvent>,
 ) {
+    let Ok((player_position, player_linear_velocity)) = player_q.get_single() else {
+        return;
+    };
+
     for (actor, mut action_state, move_to, span) in &mut action_query {
         let _guard = span.span().enter();
 
@@ -172,11 +312,13 @@ pub fn move_towards_player_action_system(
                 *action_state = ActionState::Executing;
             }
             ActionState::Executing => {
-                let (actor_position, actor_linear_velocity) =
-                    enemies.get_mut(actor.0).expect("actor has no position");
+                let Ok((actor_position, actor_linear_velocity, drive)) = enemies.get(actor.0)
+                else {
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
                 trace!("Actor position: {:?}", actor_position);
 
-                let (player_ent, player_position, player_linear_velocity) = player_q.single();
                 let delta =
                     (player_position.translation() - actor_position.translation()).truncate();
                 let distance = delta.length();
@@ -184,18 +326,17 @@ pub fn move_towards_player_action_system(
                 if distance > MAX_DISTANCE {
                     trace!("Thrusting Closer.");
 
-                    let step_size = time.delta_secs() * move_to.speed;
-                    let step = delta.normalize() * step_size.min(distance);
-
-                    // Try and match player velocity?
-                    let delta_velocity = actor_linear_velocity.xy() - player_linear_velocity.xy();
-
-                    let mix = step.lerp(delta_velocity.as_vec2(), 1.0 / (distance - MAX_DISTANCE));
+                    // Match the player's velocity on top of closing in.
+                    let desired =
+                        delta.normalize() * move_to.speed + player_linear_velocity.0.as_vec2();
 
-                    thrust_events.send(RCSThrustVectorEvent {
-                        entity: actor.0,
-                        thrust_vector: mix,
-                    });
+                    steer(
+                        &mut commands,
+                        actor.0,
+                        drive,
+                        actor_linear_velocity,
+                        desired,
+                    );
                 } else {
                     *action_state = ActionState::Success;
                 }
@@ -213,6 +354,292 @@ pub fn move_towards_player_action_system(
     }
 }
 
+pub fn orbit_action_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_q: Query<(&GlobalTransform, &LinearVelocity), With<Player>>,
+    enemies: Query<(&GlobalTransform, &LinearVelocity, &EnemyDrive), Without<Player>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &mut Orbit, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, mut orbit, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (Ok((actor_gt, velocity, drive)), Ok((player_gt, player_velocity))) =
+                    (enemies.get(*actor), player_q.get_single())
+                else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                let offset = (actor_gt.translation() - player_gt.translation()).truncate();
+                let outwards = offset.normalize_or_zero();
+                let correction =
+                    (orbit.radius - offset.length()).clamp(-drive.max_speed, drive.max_speed);
+
+                // Circle around, while drifting back onto the orbit.
+                let desired = outwards.perp() * drive.max_speed
+                    + outwards * correction
+                    + player_velocity.0.as_vec2();
+                steer(&mut commands, *actor, drive, velocity, desired);
+
+                orbit.duration -= time.delta_secs();
+                if orbit.duration <= 0.0 {
+                    *state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn flee_action_system(
+    mut commands: Commands,
+    player_q: Query<&GlobalTransform, With<Player>>,
+    enemies: Query<(&GlobalTransform, &LinearVelocity, &EnemyDrive), Without<Player>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &Flee, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, flee, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Fleeing from the player!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (Ok((actor_gt, velocity, drive)), Ok(player_gt)) =
+                    (enemies.get(*actor), player_q.get_single())
+                else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                let offset = (actor_gt.translation() - player_gt.translation()).truncate();
+                if offset.length() >= flee.distance {
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let desired = offset.normalize_or_zero() * drive.max_speed;
+                steer(&mut commands, *actor, drive, velocity, desired);
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn regroup_action_system(
+    mut commands: Commands,
+    enemies: Query<(Entity, &GlobalTransform, &LinearVelocity, &EnemyDrive), With<Enemy>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &Regroup, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, regroup, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Regrouping!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Ok((_, actor_gt, velocity, drive)) = enemies.get(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                let position = actor_gt.translation().truncate();
+
+                let allies = enemies
+                    .iter()
+                    .filter(|(ally, ..)| ally != actor)
+                    .map(|(ally, gt, ..)| (ally, gt.translation().truncate()));
+
+                let Some((_, ally_position, distance)) = nearest(position, allies) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                if distance <= regroup.radius {
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let desired = (ally_position - position).normalize_or_zero() * drive.max_speed;
+                steer(&mut commands, *actor, drive, velocity, desired);
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn steal_ore_action_system(
+    mut commands: Commands,
+    enemies: Query<(&GlobalTransform, &LinearVelocity, &EnemyDrive), With<Enemy>>,
+    collectibles: Query<(Entity, &GlobalTransform), With<Collectible>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &StealOre, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, steal, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Going for the ore!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Ok((actor_gt, velocity, drive)) = enemies.get(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                let position = actor_gt.translation().truncate();
+
+                let chunks = collectibles
+                    .iter()
+                    .map(|(chunk, gt)| (chunk, gt.translation().truncate()));
+
+                let Some((chunk, chunk_position, distance)) = nearest(position, chunks) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                if distance <= steal.reach {
+                    commands.entity(chunk).despawn_recursive();
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let desired = (chunk_position - position).normalize_or_zero() * drive.max_speed;
+                steer(&mut commands, *actor, drive, velocity, desired);
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn bomb_module_action_system(
+    mut commands: Commands,
+    enemies: Query<(&GlobalTransform, &LinearVelocity, &EnemyDrive, &Health), With<Enemy>>,
+    modules: Query<(Entity, &GlobalTransform), (With<SpaceStationModuleType>, With<Health>)>,
+    mut action_query: Query<(&Actor, &mut ActionState, &BombModule, &ActionSpan)>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for (Actor(actor), mut state, bomb, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Going for the station!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Ok((actor_gt, velocity, drive, health)) = enemies.get(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                // Already went off, waiting to be cleaned up.
+                if health.current() <= 0.0 {
+                    continue;
+                }
+                let position = actor_gt.translation().truncate();
+
+                let targets = modules
+                    .iter()
+                    .map(|(module, gt)| (module, gt.translation().truncate()));
+
+                let Some((module, module_position, distance)) = nearest(position, targets) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                if distance <= bomb.reach {
+                    damage_events.send(DamageEvent {
+                        entity: module,
+                        damage: bomb.damage,
+                        damage_type: DamageType::Explosive,
+                        source: Some(*actor),
+                    });
+                    // The bomb goes off with the bomber, which dies like any other enemy.
+                    damage_events.send(DamageEvent::lethal(
+                        *actor,
+                        DamageType::Explosive,
+                        Some(*actor),
+                    ));
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let desired = (module_position - position).normalize_or_zero() * drive.max_speed;
+                steer(&mut commands, *actor, drive, velocity, desired);
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn launch_drone_action_system(
+    mut commands: Commands,
+    mut carriers: Query<(&mut Carrier, &GlobalTransform, &LinearVelocity)>,
+    mut action_query: Query<(&Actor, &mut ActionState, &ActionSpan), With<LaunchDrone>>,
+    registry: Res<EnemyArchetypeRegistry>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (Actor(actor), mut state, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Ok((mut carrier, carrier_gt, velocity)) = carriers.get_mut(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                let Some(drone) = registry.get(&carrier.drone).filter(|_| carrier.is_ready())
+                else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                debug!("Launching a drone!");
+                let position = carrier_gt.translation().truncate();
+                let drone_ent =
+                    spawn_enemy(&mut commands, drone, position, &mut meshes, &mut materials);
+                commands.entity(drone_ent).insert(*velocity);
+
+                carrier.drones.push(drone_ent);
+                carrier.cooldown.reset();
+                *state = ActionState::Success;
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
 pub fn hostility_system(time: Res<Time>, mut hostilities: Query<&mut Hostility>) {
     for mut hostility in &mut hostilities {
         hostility.hostility +=
@@ -222,6 +649,7 @@ pub fn hostility_system(time: Res<Time>, mut hostilities: Query<&mut Hostility>)
     }
 }
 
+// SCORERS
 pub fn hostility_scorer_system(
     hostilities: Query<&Hostility>,
     mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<Hostile>>,
@@ -241,3 +669,83 @@ pub fn hostility_scorer_system(
         }
     }
 }
+
+pub fn low_health_scorer_system(
+    healths: Query<&Health>,
+    mut query: Query<(&Actor, &mut Score, &LowHealth)>,
+) {
+    for (Actor(actor), mut score, low_health) in query.iter_mut() {
+        if let Ok(health) = healths.get(*actor) {
+            let low = health.current_percent() < low_health.threshold;
+            score.set(if low { 1.0 } else { 0.0 });
+        }
+    }
+}
+
+/// Only scores when there's another enemy close enough to go back to,
+/// within four times the radius.
+pub fn isolated_scorer_system(
+    enemies: Query<(Entity, &GlobalTransform), With<Enemy>>,
+    mut query: Query<(&Actor, &mut Score, &Isolated)>,
+) {
+    for (Actor(actor), mut score, isolated) in query.iter_mut() {
+        let Ok((_, actor_gt)) = enemies.get(*actor) else {
+            continue;
+        };
+
+        let allies = enemies
+            .iter()
+            .filter(|(ally, _)| ally != actor)
+            .map(|(ally, gt)| (ally, gt.translation().truncate()));
+
+        let straying =
+            nearest(actor_gt.translation().truncate(), allies).is_some_and(|(_, _, distance)| {
+                distance > isolated.radius && distance <= isolated.radius * 4.0
+            });
+        score.set(if straying { 1.0 } else { 0.0 });
+    }
+}
+
+pub fn ore_in_sight_scorer_system(
+    positions: Query<&GlobalTransform>,
+    collectibles: Query<&GlobalTransform, With<Collectible>>,
+    mut query: Query<(&Actor, &mut Score, &OreInSight)>,
+) {
+    for (Actor(actor), mut score, ore_in_sight) in query.iter_mut() {
+        let Ok(actor_gt) = positions.get(*actor) else {
+            continue;
+        };
+
+        let in_sight = collectibles
+            .iter()
+            .any(|gt| gt.translation().distance(actor_gt.translation()) <= ore_in_sight.range);
+        score.set(if in_sight { 1.0 } else { 0.0 });
+    }
+}
+
+pub fn station_in_sight_scorer_system(
+    positions: Query<&GlobalTransform>,
+    modules: Query<&GlobalTransform, With<SpaceStationModuleType>>,
+    mut query: Query<(&Actor, &mut Score, &StationInSight)>,
+) {
+    for (Actor(actor), mut score, station_in_sight) in query.iter_mut() {
+        let Ok(actor_gt) = positions.get(*actor) else {
+            continue;
+        };
+
+        let in_sight = modules
+            .iter()
+            .any(|gt| gt.translation().distance(actor_gt.translation()) <= station_in_sight.range);
+        score.set(if in_sight { 1.0 } else { 0.0 });
+    }
+}
+
+pub fn hangar_ready_scorer_system(
+    carriers: Query<&Carrier>,
+    mut query: Query<(&Actor, &mut Score), With<HangarReady>>,
+) {
+    for (Actor(actor), mut score) in query.iter_mut() {
+        let ready = carriers.get(*actor).is_ok_and(Carrier::is_ready);
+        score.set(if ready { 1.0 } else { 0.0 });
+    }
+}
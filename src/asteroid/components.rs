@@ -1,15 +1,95 @@
+use bevy::color::palettes::css::{GOLD, GRAY, LIMEGREEN, SILVER};
 use bevy::prelude::*;
 use bevy::utils::HashMap;
-use rand::{distributions::Distribution, seq::SliceRandom};
+use rand::{distributions::Distribution, seq::SliceRandom, Rng};
 use rand_distr::Normal;
-use std::{cmp::Ordering, fmt};
+use serde::Deserialize;
+use std::{cmp::Ordering, f32::consts::TAU, fmt};
+
+use crate::items::components::ItemId;
+
+/// Pieces smaller than this are slivers left by cutting along an edge and are dropped.
+const MIN_FRAGMENT_AREA: f32 = 1.0;
+/// Concave outlines use more vertices than convex ones of the same size, to fit the bumps.
+const CONCAVE_VERTICES_PER_SIDE: usize = 3;
+/// Octaves of noise displacing a concave outline, each half as strong as the one before.
+const NOISE_OCTAVES: u32 = 3;
+/// Displacement of the first noise octave as a fraction of the radius.
+const NOISE_AMPLITUDE: f32 = 0.25;
+const CRATER_VERTICES: usize = 8;
+/// Largest distance in pixels a carved outline is allowed to stray when it's simplified,
+/// which keeps repeated craters from piling up vertices.
+const CRATER_SIMPLIFY_TOLERANCE: f32 = 0.5;
+/// Health of an asteroid per square pixel of its outline.
+/// Tuned so the laser takes several seconds to split a large asteroid.
+const HEALTH_PER_AREA: f32 = 0.1;
+/// Fraction of an asteroid's mass that can be mined off it as ore chunks.
+const ORE_BUDGET_FRACTION: f32 = 0.5;
 
 #[derive(Component)]
 pub struct Splittable(pub f32);
 
+/// Where an asteroid was last hit and the surface normal there, in world space.
+/// A splitting asteroid cracks along the normal through this point.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LastImpact {
+    pub point: Vec2,
+    pub normal: Vec2,
+}
+
+/// Laser energy an asteroid soaked up since its last crater was carved.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct Ablation(pub f32);
+
+/// The ore an asteroid can still give off when lasered, as mass,
+/// and the laser exposure built up towards the next ore chunk.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct OreBudget {
+    pub remaining: f32,
+    pub exposure: f32,
+}
+
+impl OreBudget {
+    pub fn for_asteroid(asteroid: &Asteroid) -> Self {
+        Self {
+            remaining: Asteroid::polygon_area(&asteroid.polygon.vertices).abs()
+                * ORE_BUDGET_FRACTION,
+            exposure: 0.0,
+        }
+    }
+
+    /// The part of the budget a piece of a split asteroid takes with it.
+    pub fn share(&self, fraction: f32) -> Self {
+        Self {
+            remaining: self.remaining * fraction,
+            exposure: 0.0,
+        }
+    }
+
+    /// Adds laser energy, at the given ore chunks per unit of energy,
+    /// and returns how many ore chunks that knocks loose.
+    pub fn expose(&mut self, energy: f32, ore_yield: f32) -> usize {
+        self.exposure += energy * ore_yield;
+
+        let loosened = self.exposure.floor();
+        self.exposure -= loosened;
+        loosened as usize
+    }
+
+    /// Takes the mass of an ore chunk out of the budget, unless there isn't enough left.
+    pub fn take(&mut self, mass: f32) -> bool {
+        if self.remaining < mass {
+            return false;
+        }
+
+        self.remaining -= mass;
+        true
+    }
+}
+
 #[derive(Component, Clone, Debug)]
 pub struct Asteroid {
-    // pub size: AsteroidSize,
+    pub size: AsteroidSize,
     // pub health: Health,
     pub composition: AsteroidComposition,
     pub polygon: BoxedPolygon,
@@ -34,20 +114,163 @@ impl Asteroid {
         poly.signed_area()
     }
 
-    pub fn new_with(radius: f32, comp: AsteroidComposition) -> Self {
-        let asteroid_polygon = Self::generate_shape_from_size(radius);
-        let poly_area = Self::polygon_area(asteroid_polygon.vertices.iter().as_slice());
+    /// Bigger asteroids take longer to wear down, whatever their size tier.
+    pub fn max_health(&self) -> f32 {
+        Self::polygon_area(&self.polygon.vertices).abs() * HEALTH_PER_AREA
+    }
+
+    pub fn new_with(size: AsteroidSize, comp: AsteroidComposition, rng: &mut impl Rng) -> Self {
+        Self {
+            size,
+            composition: comp,
+            polygon: Self::generate_shape_from_size(size, rng),
+            radius: size.radius(),
+        }
+    }
+
+    /// Like `new_with`, but with a lumpy, concave outline instead of a convex polygon.
+    pub fn new_concave(size: AsteroidSize, comp: AsteroidComposition, rng: &mut impl Rng) -> Self {
+        use geo::Centroid;
 
-        // Compute Health from Generated Shape Mass?
+        let outline = Self::make_noise_polygon_coords(
+            size.num_sides() * CONCAVE_VERTICES_PER_SIDE,
+            size.radius(),
+            rng,
+        );
+        let centroid = to_geo_polygon(&outline)
+            .centroid()
+            .map_or(Vec2::ZERO, |centroid| Vec2::new(centroid.x(), centroid.y()));
+        let vertices: Vec<Vec2> = outline.iter().map(|vertex| *vertex - centroid).collect();
 
         Self {
-            // size,
+            size,
             composition: comp,
-            polygon: asteroid_polygon,
-            radius,
+            radius: outer_radius(&vertices),
+            polygon: BoxedPolygon {
+                vertices: vertices.into(),
+            },
         }
     }
 
+    pub fn is_convex(&self) -> bool {
+        use geo::IsConvex;
+
+        to_geo_polygon(&self.polygon.vertices)
+            .exterior()
+            .is_convex()
+    }
+
+    /// Cuts the asteroid along the line through `point` with the given `direction`, both in the
+    /// asteroid's local space. Returns each piece centered on its own centroid, along with the
+    /// offset of that centroid from the asteroid's center. The pieces keep the asteroid's
+    /// size and composition and together cover its whole area. Concave asteroids can break
+    /// into more than one piece on each side of the cut.
+    pub fn fracture(&self, point: Vec2, direction: Vec2) -> Vec<(Asteroid, Vec2)> {
+        use geo::{Area, BooleanOps, Centroid};
+
+        let direction = direction.normalize_or(Vec2::X);
+        let normal = direction.perp();
+        // Far enough that each half plane covers the whole asteroid on its side of the cut.
+        let extent = 4.0 * self.radius + point.length();
+        let asteroid_polygon = to_geo_polygon(&self.polygon.vertices);
+
+        [normal, -normal]
+            .into_iter()
+            .flat_map(|side| {
+                let half_plane = to_geo_polygon(&[
+                    point - direction * extent,
+                    point + direction * extent,
+                    point + direction * extent + side * extent,
+                    point - direction * extent + side * extent,
+                ]);
+
+                asteroid_polygon.intersection(&half_plane)
+            })
+            .filter(|piece| piece.unsigned_area() > MIN_FRAGMENT_AREA)
+            .filter_map(|piece| {
+                let centroid = piece.centroid()?;
+                let offset = Vec2::new(centroid.x(), centroid.y());
+
+                let vertices: Vec<Vec2> = from_geo_polygon(&piece)
+                    .into_iter()
+                    .map(|vertex| vertex - offset)
+                    .collect();
+
+                Some((
+                    Asteroid {
+                        size: self.size,
+                        composition: self.composition.clone(),
+                        radius: outer_radius(&vertices),
+                        polygon: BoxedPolygon {
+                            vertices: vertices.into(),
+                        },
+                    },
+                    offset,
+                ))
+            })
+            .collect()
+    }
+
+    /// Blasts a roughly circular crater into the outline at `center`, in local space.
+    /// The asteroid keeps the largest piece left over. Any other pieces the crater cuts off
+    /// are returned centered on their own centroid, with the offset of that centroid from
+    /// the asteroid's center. Returns None, leaving the shape alone, if nothing would be left.
+    pub fn carve_crater(&mut self, center: Vec2, radius: f32) -> Option<Vec<(Asteroid, Vec2)>> {
+        use geo::{Area, BooleanOps, Centroid, Simplify};
+
+        let crater: Vec<Vec2> = (0..CRATER_VERTICES)
+            .map(|i| center + Vec2::from_angle(TAU * i as f32 / CRATER_VERTICES as f32) * radius)
+            .collect();
+
+        let mut pieces: Vec<geo::Polygon<f32>> = to_geo_polygon(&self.polygon.vertices)
+            .difference(&to_geo_polygon(&crater))
+            .into_iter()
+            .map(|piece| piece.simplify(&CRATER_SIMPLIFY_TOLERANCE))
+            .filter(|piece| {
+                piece.unsigned_area() > MIN_FRAGMENT_AREA && piece.exterior().coords().count() > 3
+            })
+            .collect();
+        pieces.sort_by(|a, b| b.unsigned_area().total_cmp(&a.unsigned_area()));
+
+        if pieces.is_empty() {
+            return None;
+        }
+
+        let vertices = from_geo_polygon(&pieces.remove(0));
+        self.radius = outer_radius(&vertices);
+        self.polygon = BoxedPolygon {
+            vertices: vertices.into(),
+        };
+
+        Some(
+            pieces
+                .into_iter()
+                .filter_map(|piece| {
+                    let centroid = piece.centroid()?;
+                    let offset = Vec2::new(centroid.x(), centroid.y());
+
+                    let vertices: Vec<Vec2> = from_geo_polygon(&piece)
+                        .into_iter()
+                        .map(|vertex| vertex - offset)
+                        .collect();
+                    let radius = outer_radius(&vertices);
+
+                    Some((
+                        Asteroid {
+                            size: AsteroidSize::for_radius(radius),
+                            composition: self.composition.clone(),
+                            radius,
+                            polygon: BoxedPolygon {
+                                vertices: vertices.into(),
+                            },
+                        },
+                        offset,
+                    ))
+                })
+                .collect(),
+        )
+    }
+
     pub fn primary_composition(&self) -> AsteroidMaterial {
         self.composition.most_abundant()
     }
@@ -56,35 +279,67 @@ impl Asteroid {
         self.polygon.clone()
     }
 
-    fn generate_shape_from_size(radius: f32) -> BoxedPolygon {
+    fn generate_shape_from_size(size: AsteroidSize, rng: &mut impl Rng) -> BoxedPolygon {
         BoxedPolygon {
-            vertices: Self::make_valtr_convex_polygon_coords(6, radius).into(),
+            vertices: Self::make_valtr_convex_polygon_coords(size.num_sides(), size.radius(), rng)
+                .into(),
         }
     }
 
+    /// Places vertices evenly around a circle and pushes each one in or out by a few octaves
+    /// of smooth periodic noise. The outline stays star shaped around the origin, so it never
+    /// crosses itself, but it is usually concave.
+    fn make_noise_polygon_coords(
+        num_vertices: usize,
+        radius: f32,
+        rng: &mut impl Rng,
+    ) -> Vec<Vec2> {
+        // Whole number frequencies so the noise wraps around seamlessly.
+        let octaves: Vec<(f32, f32, f32)> = (1..=NOISE_OCTAVES)
+            .map(|octave| {
+                let frequency = (2 * octave + rng.gen_range(0..2)) as f32;
+                let amplitude = NOISE_AMPLITUDE / octave as f32;
+                let phase = rng.gen_range(0.0..TAU);
+                (frequency, amplitude, phase)
+            })
+            .collect();
+
+        (0..num_vertices)
+            .map(|i| {
+                let angle = TAU * i as f32 / num_vertices as f32;
+                let displacement: f32 = octaves
+                    .iter()
+                    .map(|(frequency, amplitude, phase)| {
+                        amplitude * (frequency * angle + phase).sin()
+                    })
+                    .sum();
+
+                Vec2::from_angle(angle) * radius * (1.0 + displacement)
+            })
+            .collect()
+    }
+
     // TODO: comment this well...
-    fn make_valtr_convex_polygon_coords(num_sides: usize, radius: f32) -> Vec<Vec2> {
+    fn make_valtr_convex_polygon_coords(
+        num_sides: usize,
+        radius: f32,
+        rng: &mut impl Rng,
+    ) -> Vec<Vec2> {
         let mut xs: Vec<f32> = vec![];
         let mut ys: Vec<f32> = vec![];
 
         for _ in 0..num_sides {
-            xs.push(2.0 * radius * rand::random::<f32>());
-            ys.push(2.0 * radius * rand::random::<f32>());
+            xs.push(2.0 * radius * rng.gen::<f32>());
+            ys.push(2.0 * radius * rng.gen::<f32>());
         }
 
-        // might be different than guide...
         xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        let min_xs = xs[0];
-        let max_xs = xs[xs.len() - 1];
-        let min_ys = ys[0];
-        let max_ys = ys[ys.len() - 1];
+        ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
-        let vec_xs = make_vector_chain(xs, min_xs, max_xs);
-        let mut vec_ys = make_vector_chain(ys, min_ys, max_ys);
+        let vec_xs = make_vector_chain(&xs, rng);
+        let mut vec_ys = make_vector_chain(&ys, rng);
 
-        vec_ys.shuffle(&mut rand::thread_rng());
+        vec_ys.shuffle(rng);
 
         let mut vecs: Vec<(f32, f32)> = vec_xs.into_iter().zip(vec_ys).collect();
 
@@ -117,24 +372,28 @@ impl Asteroid {
             poly_coords.push(Vec2 { x, y })
         }
 
-        fn make_vector_chain(values_array: Vec<f32>, min_value: f32, max_value: f32) -> Vec<f32> {
+        // Splits the sorted values between two chains running from the minimum to the maximum,
+        // returning the steps along both chains. There is one step per value and they sum to zero.
+        fn make_vector_chain(sorted_values: &[f32], rng: &mut impl Rng) -> Vec<f32> {
             let mut vector_chain: Vec<f32> = vec![];
 
-            let mut last_min = min_value;
-            let mut last_max = max_value;
+            let min_value = sorted_values[0];
+            let max_value = sorted_values[sorted_values.len() - 1];
+            let mut last_top = min_value;
+            let mut last_bottom = min_value;
 
-            for value in values_array {
-                if rand::random::<f32>() > 0.5 {
-                    vector_chain.push(value - last_min);
-                    last_min = value;
+            for &value in &sorted_values[1..sorted_values.len() - 1] {
+                if rng.gen::<f32>() > 0.5 {
+                    vector_chain.push(value - last_top);
+                    last_top = value;
                 } else {
-                    vector_chain.push(last_max - value);
-                    last_max = value;
+                    vector_chain.push(last_bottom - value);
+                    last_bottom = value;
                 }
             }
 
-            vector_chain.push(max_value - last_min);
-            vector_chain.push(last_max - max_value);
+            vector_chain.push(max_value - last_top);
+            vector_chain.push(last_bottom - max_value);
 
             vector_chain
         }
@@ -179,7 +438,48 @@ impl Asteroid {
     }
 }
 
-#[derive(Component, Reflect, Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd)]
+fn to_geo_polygon(vertices: &[Vec2]) -> geo::Polygon<f32> {
+    use geo::{Coord, LineString, Polygon};
+
+    Polygon::new(
+        vertices
+            .iter()
+            .map(|vertex| Coord {
+                x: vertex.x,
+                y: vertex.y,
+            })
+            .collect::<LineString<f32>>(),
+        vec![],
+    )
+}
+
+/// Returns the outline of a polygon, counter-clockwise and without any holes.
+fn from_geo_polygon(polygon: &geo::Polygon<f32>) -> Vec<Vec2> {
+    use geo::orient::{Direction, Orient};
+
+    let mut vertices: Vec<Vec2> = polygon
+        .orient(Direction::Default)
+        .exterior()
+        .coords()
+        .map(|coord| Vec2::new(coord.x, coord.y))
+        .collect();
+    // The exterior ring repeats its first vertex at the end.
+    vertices.pop();
+
+    vertices
+}
+
+/// Distance from the origin to the farthest vertex.
+fn outer_radius(vertices: &[Vec2]) -> f32 {
+    vertices
+        .iter()
+        .map(|vertex| vertex.length())
+        .fold(0.0, f32::max)
+}
+
+#[derive(
+    Component, Reflect, Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Deserialize,
+)]
 pub enum AsteroidMaterial {
     #[default]
     Rock,
@@ -188,6 +488,28 @@ pub enum AsteroidMaterial {
     Gold,
 }
 
+impl AsteroidMaterial {
+    /// The id of the ore item collected from this material.
+    pub fn item_id(&self) -> ItemId {
+        match *self {
+            AsteroidMaterial::Rock => ItemId::from("rock"),
+            AsteroidMaterial::Iron => ItemId::from("iron_ore"),
+            AsteroidMaterial::Silver => ItemId::from("silver_ore"),
+            AsteroidMaterial::Gold => ItemId::from("gold_ore"),
+        }
+    }
+
+    /// The color asteroids mostly made of this material are drawn with.
+    pub fn color(&self) -> Srgba {
+        match *self {
+            AsteroidMaterial::Iron => GRAY,
+            AsteroidMaterial::Silver => SILVER,
+            AsteroidMaterial::Gold => GOLD,
+            AsteroidMaterial::Rock => LIMEGREEN,
+        }
+    }
+}
+
 impl fmt::Display for AsteroidMaterial {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -199,7 +521,7 @@ impl fmt::Display for AsteroidMaterial {
     }
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum AsteroidSize {
     OreChunk,
     Small,
@@ -208,6 +530,49 @@ pub enum AsteroidSize {
 }
 
 impl AsteroidSize {
+    /// The tier the pieces of a split asteroid of this size fall into.
+    /// Ore chunks are the smallest tier and don't split any further.
+    pub fn smaller(self) -> Option<Self> {
+        match self {
+            Self::OreChunk => None,
+            Self::Small => Some(Self::OreChunk),
+            Self::Medium => Some(Self::Small),
+            Self::Large => Some(Self::Medium),
+        }
+    }
+
+    /// The largest tier a piece reaching out `radius` from its center passes for.
+    pub fn for_radius(radius: f32) -> Self {
+        [Self::Large, Self::Medium, Self::Small]
+            .into_iter()
+            .find(|size| radius >= size.radius())
+            .unwrap_or(Self::OreChunk)
+    }
+
+    /// Ore chunks can be flown into to collect their ore.
+    pub fn is_collectible(self) -> bool {
+        self == Self::OreChunk
+    }
+
+    /// Fraction of its health below which the asteroid splits into the next smaller tier.
+    pub fn split_threshold(self) -> Option<f32> {
+        match self {
+            Self::OreChunk => None,
+            Self::Small => Some(0.4),
+            Self::Medium | Self::Large => Some(0.5),
+        }
+    }
+
+    /// Ore chunks knocked loose from the asteroid per unit of laser energy.
+    pub fn ore_yield(self) -> f32 {
+        match self {
+            Self::OreChunk => 0.0,
+            Self::Small => 0.01,
+            Self::Medium => 0.015,
+            Self::Large => 0.02,
+        }
+    }
+
     pub fn radius(self) -> f32 {
         match self {
             Self::OreChunk => 25.0,
@@ -233,32 +598,11 @@ pub struct AsteroidComposition {
 }
 
 impl AsteroidComposition {
-    pub fn new_with_distance(distance: f32) -> Self {
-        const MIN_DISTANCE: f32 = 0.0;
-        const MAX_DISTANCE: f32 = 100000.0;
-
-        let percentage =
-            ((distance - MIN_DISTANCE) / (MAX_DISTANCE - MIN_DISTANCE)).clamp(0.0, 1.0);
-
-        let mut near_composition: HashMap<AsteroidMaterial, f32> = HashMap::new();
-        near_composition.insert(AsteroidMaterial::Iron, 0.95);
-        near_composition.insert(AsteroidMaterial::Silver, 0.04);
-        near_composition.insert(AsteroidMaterial::Gold, 0.01);
-
-        let mut far_composition: HashMap<AsteroidMaterial, f32> = HashMap::new();
-        far_composition.insert(AsteroidMaterial::Iron, 1.0);
-        far_composition.insert(AsteroidMaterial::Silver, 2.0);
-        far_composition.insert(AsteroidMaterial::Gold, 2.0);
-
-        let mut composition = HashMap::new();
-
-        for near in near_composition.iter() {
-            let far = far_composition.get(near.0).unwrap();
-
-            composition.insert(*near.0, near.1 + (far - near.1) * percentage);
+    /// Builds a composition from relative weights, ie. the material profile of an asteroid field.
+    pub fn from_weights(weights: impl IntoIterator<Item = (AsteroidMaterial, f32)>) -> Self {
+        Self {
+            composition: weights.into_iter().collect(),
         }
-
-        Self { composition }
     }
 
     pub fn most_abundant(&self) -> AsteroidMaterial {
@@ -278,15 +622,17 @@ impl AsteroidComposition {
             .collect::<HashMap<AsteroidMaterial, f32>>()
     }
 
-    pub fn jitter(&self) -> AsteroidComposition {
-        let mut rng = rand::thread_rng();
+    pub fn jitter(&self, rng: &mut impl Rng) -> AsteroidComposition {
         let normal = Normal::new(0.0, 0.05).unwrap();
 
+        // Sampled in a fixed order so the same rng state always gives the same composition.
+        let mut percent_composition: Vec<_> = self.percent_composition().into_iter().collect();
+        percent_composition.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
         AsteroidComposition {
-            composition: self
-                .percent_composition()
+            composition: percent_composition
                 .into_iter()
-                .map(|(k, v)| (k, (v + normal.sample(&mut rng)).clamp(0.0, f32::MAX)))
+                .map(|(k, v)| (k, (v + normal.sample(rng)).clamp(0.0, f32::MAX)))
                 .collect(),
         }
     }
@@ -301,14 +647,216 @@ impl fmt::Debug for AsteroidComposition {
     }
 }
 
+#[cfg(test)]
+fn test_composition() -> AsteroidComposition {
+    AsteroidComposition::from_weights([
+        (AsteroidMaterial::Iron, 0.8),
+        (AsteroidMaterial::Silver, 0.15),
+        (AsteroidMaterial::Gold, 0.05),
+    ])
+}
+
 #[test]
 fn test_most_abundant() {
+    assert_eq!(test_composition().most_abundant(), AsteroidMaterial::Iron);
     assert_eq!(
-        AsteroidComposition::new_with_distance(0.0).most_abundant(),
-        AsteroidMaterial::Iron
+        AsteroidComposition::from_weights([
+            (AsteroidMaterial::Iron, 1.0),
+            (AsteroidMaterial::Silver, 2.0),
+            (AsteroidMaterial::Gold, 3.0),
+        ])
+        .most_abundant(),
+        AsteroidMaterial::Gold
+    );
+}
+
+#[test]
+fn test_same_seed_gives_same_asteroid() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let generate = |seed| {
+        let mut rng = SeededRng::new(WorldSeed(seed));
+        let composition = test_composition().jitter(rng.stream(RngStream::AsteroidComposition));
+        let asteroid = Asteroid::new_with(
+            AsteroidSize::Large,
+            composition,
+            rng.stream(RngStream::AsteroidShapes),
+        );
+
+        (
+            asteroid.polygon.vertices,
+            asteroid.composition.percent_composition(),
+        )
+    };
+
+    assert_eq!(generate(42), generate(42));
+    assert_ne!(generate(42).0, generate(43).0);
+}
+
+#[test]
+fn test_health_follows_area() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let mut rng = SeededRng::new(WorldSeed(3));
+    let large = Asteroid::new_with(
+        AsteroidSize::Large,
+        test_composition(),
+        rng.stream(RngStream::AsteroidShapes),
     );
+    let small = Asteroid::new_with(
+        AsteroidSize::Small,
+        test_composition(),
+        rng.stream(RngStream::AsteroidShapes),
+    );
+
     assert_eq!(
-        AsteroidComposition::new_with_distance(10000.0).most_abundant(),
-        AsteroidMaterial::Gold
+        large.max_health(),
+        Asteroid::polygon_area(&large.polygon.vertices).abs() * HEALTH_PER_AREA
     );
+    assert!(large.max_health() > 2.0 * small.max_health());
+}
+
+#[test]
+fn test_fracture_conserves_area_and_composition() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let mut rng = SeededRng::new(WorldSeed(7));
+    let asteroid = Asteroid::new_with(
+        AsteroidSize::Large,
+        test_composition(),
+        rng.stream(RngStream::AsteroidShapes),
+    );
+    let area = Asteroid::polygon_area(&asteroid.polygon.vertices);
+
+    let pieces = asteroid.fracture(Vec2::new(5.0, -3.0), Vec2::new(1.0, 2.0));
+    assert_eq!(pieces.len(), 2);
+
+    let pieces_area: f32 = pieces
+        .iter()
+        .map(|(piece, _)| Asteroid::polygon_area(&piece.polygon.vertices))
+        .sum();
+    assert!((pieces_area - area).abs() / area < 0.001);
+
+    // The area weighted centroids of the pieces balance out at the asteroid's center.
+    let center_of_mass: Vec2 = pieces
+        .iter()
+        .map(|(piece, offset)| *offset * Asteroid::polygon_area(&piece.polygon.vertices))
+        .sum::<Vec2>()
+        / area;
+    assert!(center_of_mass.length() < 0.1);
+
+    for (piece, _) in pieces.iter() {
+        assert_eq!(
+            piece.composition.percent_composition(),
+            asteroid.composition.percent_composition()
+        );
+    }
+}
+
+#[test]
+fn test_size_tiers_set_vertex_count() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let mut rng = SeededRng::new(WorldSeed(3));
+
+    for size in [
+        AsteroidSize::OreChunk,
+        AsteroidSize::Small,
+        AsteroidSize::Medium,
+        AsteroidSize::Large,
+    ] {
+        let asteroid = Asteroid::new_with(
+            size,
+            test_composition(),
+            rng.stream(RngStream::AsteroidShapes),
+        );
+
+        assert_eq!(asteroid.polygon.vertices.len(), size.num_sides());
+        assert!(Asteroid::polygon_area(&asteroid.polygon.vertices) > 0.0);
+    }
+}
+
+#[test]
+fn test_concave_shapes_and_craters() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let mut rng = SeededRng::new(WorldSeed(11));
+    let mut asteroid = Asteroid::new_concave(
+        AsteroidSize::Large,
+        test_composition(),
+        rng.stream(RngStream::AsteroidShapes),
+    );
+    assert!(!asteroid.is_convex());
+
+    let area = Asteroid::polygon_area(&asteroid.polygon.vertices);
+    let surface_point = asteroid.polygon.vertices[0];
+    let vertex_count = asteroid.polygon.vertices.len();
+    assert!(asteroid.carve_crater(surface_point, 10.0).is_some());
+
+    let carved_area = Asteroid::polygon_area(&asteroid.polygon.vertices);
+    assert!(carved_area < area);
+    assert!(carved_area > area * 0.9);
+
+    // Carving the same spot over and over doesn't keep adding vertices.
+    for _ in 0..20 {
+        asteroid.carve_crater(surface_point, 2.0);
+    }
+    assert!(asteroid.polygon.vertices.len() <= vertex_count + 2 * CRATER_VERTICES);
+}
+
+#[test]
+fn test_crater_cutting_through_leaves_fragments() {
+    let mut asteroid = Asteroid {
+        size: AsteroidSize::Medium,
+        composition: test_composition(),
+        radius: 60.0,
+        polygon: BoxedPolygon {
+            vertices: vec![
+                Vec2::new(-60.0, -5.0),
+                Vec2::new(60.0, -5.0),
+                Vec2::new(60.0, 5.0),
+                Vec2::new(-60.0, 5.0),
+            ]
+            .into(),
+        },
+    };
+    let area = Asteroid::polygon_area(&asteroid.polygon.vertices);
+
+    // Off center, so one side is clearly the larger piece.
+    let fragments = asteroid.carve_crater(Vec2::new(20.0, 0.0), 10.0).unwrap();
+    assert_eq!(fragments.len(), 1);
+
+    let (fragment, offset) = &fragments[0];
+    assert!(offset.x > 20.0);
+    assert_eq!(fragment.size, AsteroidSize::OreChunk);
+
+    let kept = Asteroid::polygon_area(&asteroid.polygon.vertices);
+    let cut_off = Asteroid::polygon_area(&fragment.polygon.vertices);
+    assert!(kept > cut_off);
+    assert!(kept + cut_off < area);
+
+    // A crater swallowing the whole asteroid leaves it alone.
+    assert!(asteroid.carve_crater(Vec2::ZERO, 500.0).is_none());
+}
+
+#[test]
+fn test_ore_budget_yield_and_depletion() {
+    let mut budget = OreBudget {
+        remaining: 250.0,
+        exposure: 0.0,
+    };
+
+    // Twice the energy knocks twice the chunks loose.
+    let low: usize = (0..100).map(|_| budget.expose(1.0, 0.25)).sum();
+    let high: usize = (0..100).map(|_| budget.expose(2.0, 0.25)).sum();
+    assert_eq!(low, 25);
+    assert_eq!(high, 50);
+
+    // The budget runs out, however long the laser stays on.
+    let taken = (0..10).filter(|_| budget.take(100.0)).count();
+    assert_eq!(taken, 2);
+    assert!(budget.remaining < 100.0);
+
+    let half = budget.share(0.5);
+    assert_eq!(half.remaining, budget.remaining * 0.5);
 }
@@ -7,8 +7,10 @@ use bevy::{
 
 use super::components::Asteroid;
 
+/// A laser hitting an asteroid: the asteroid, the hit point, the surface normal
+/// and the laser energy delivered this frame.
 #[derive(Event)]
-pub struct AblateEvent(pub Entity, pub Vec2, pub Vec2);
+pub struct AblateEvent(pub Entity, pub Vec2, pub Vec2, pub f32);
 
 #[derive(Event)]
 pub struct SpawnAsteroidEvent(pub Asteroid, pub Transform, pub LinearVelocity);
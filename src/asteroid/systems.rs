@@ -1,94 +1,59 @@
 use crate::{
-    health::{components::Health, events::DamageEvent},
-    inventory::components::{Inventory, InventoryItem},
-    items::Amount,
+    health::{
+        components::{Health, ImpactVelocity, Resistances},
+        events::{ImpactEvent, KilledEvent},
+    },
+    inventory::components::{Capacity, Inventory, InventoryItem},
+    items::{resources::ItemRegistry, Amount},
     player::components::Player,
     ui::damage_indicator::events::DamageIndicatorEvent,
 };
-use bevy::{
-    color::palettes::css::{GOLD, GRAY, LIMEGREEN, SILVER},
-    prelude::*,
-};
+use bevy::prelude::*;
 // use bevy_particle_systems::Playing;
 use avian2d::{
-    math::{Scalar, Vector, PI},
+    math::{Scalar, Vector},
     prelude::*,
 };
 use ordered_float::OrderedFloat;
 use rand::Rng;
+use std::f32::consts::{PI, TAU};
 
 use crate::{
+    asteroid_field::components::ChunkOrigin,
     collectible::components::Collectible,
-    space_station::components::SpaceStation,
     ui::context_clue::resources::{ContextClue, ContextClues},
+    world_seed::resources::{RngStream, SeededRng},
     PIXELS_PER_METER,
 };
 
 use super::{
-    components::{Asteroid, AsteroidComposition, AsteroidMaterial, AsteroidSize, Splittable},
+    components::{Ablation, Asteroid, AsteroidSize, LastImpact, OreBudget, Splittable},
     events::{AblateEvent, SpawnAsteroidEvent, SplitAsteroidEvent},
-    plugin::LASER_DAMAGE,
-    resources::{AsteroidSpawner, InventoryFullNotificationTimer},
+    resources::InventoryFullNotificationTimer,
 };
 
-// System to spawn asteroids at some distance away from the ship in random directions,
-// each asteroid with an initial velocity aimed towards the players ship
-pub fn spawn_asteroids_aimed_at_ship(
-    mut commands: Commands,
-    player_query: Query<(&Player, &GlobalTransform)>,
-    base_station_query: Query<(&SpaceStation, &GlobalTransform)>,
-    mut asteroid_spawner: ResMut<AsteroidSpawner>,
-    time: Res<Time>,
-) {
-    const SPAWN_DISTANCE: f32 = 350.0;
-
-    asteroid_spawner.timer.tick(time.delta());
-
-    if asteroid_spawner.timer.finished() {
-        asteroid_spawner.timer.reset();
-
-        let mut rng = rand::thread_rng();
-        let (_player, player_g_transform) = player_query.single();
-        let (_base_station, base_station_g_transform) = base_station_query.single();
-
-        let distance_to_base_station =
-            (player_g_transform.translation() - base_station_g_transform.translation()).length();
-        let player_position = player_g_transform.translation().truncate();
-
-        let rand_x: f32 = rng.gen_range(-PI as f32..PI as f32);
-        let rand_y: f32 = rng.gen_range(-PI as f32..PI as f32);
-        let rand_direction = Vec2::new(rand_x.cos(), rand_y.sin()).normalize();
-
-        let random_spawn_position =
-            player_position + (rand_direction * SPAWN_DISTANCE * crate::PIXELS_PER_METER as f32);
-        let direction_to_player = (player_position - random_spawn_position).normalize() * 200.0; // maybe?
-
-        let asteroid = Asteroid::new_with(
-            AsteroidSize::Large.radius(),
-            AsteroidComposition::new_with_distance(distance_to_base_station),
-        );
-        let asteroid_transform = Transform::from_translation(random_spawn_position.extend(0.0));
-        let asteroid_linear_velocity = LinearVelocity(direction_to_player.as_dvec2());
-
-        commands.trigger(SpawnAsteroidEvent(
-            asteroid,
-            asteroid_transform,
-            asteroid_linear_velocity,
-        ));
-
-    }
-}
-
-const THRESHOLD_COLLECTIBLE_MASS: f32 = 2500.0;
+/// Speed at which the pieces of a fractured asteroid drift apart from each other.
+const FRACTURE_SEPARATION_SPEED: f32 = 40.0;
+/// Laser energy an asteroid soaks up before a crater is carved into it.
+const CRATER_ENERGY: f32 = 25.0;
+/// Square pixels of asteroid a unit of laser energy blasts away.
+const CRATER_AREA_PER_ENERGY: f32 = 4.0;
+/// Largest crater radius as a fraction of the asteroid's radius.
+const MAX_CRATER_RADIUS_FRACTION: f32 = 0.15;
+/// Speed at which ore chunks are thrown off the surface where the laser hit.
+const ORE_CHUNK_EJECT_SPEED: f32 = 60.0;
+/// Impact energy per unit of an asteroid's mass that shatters it.
+const SPLIT_IMPACT_ENERGY_PER_MASS: f32 = 2000.0;
 
 pub fn tag_small_asteroids_as_collectible(
     mut commands: Commands,
-    mut asteroid_query: Query<(Entity, &Mass), With<Asteroid>>,
+    asteroid_query: Query<(Entity, &Asteroid), Without<Collectible>>,
 ) {
-    for (ent, mass) in asteroid_query.iter_mut() {
-        if mass.0 <= THRESHOLD_COLLECTIBLE_MASS {
+    for (ent, asteroid) in asteroid_query.iter() {
+        if asteroid.size.is_collectible() {
             if let Some(mut ent_commands) = commands.get_entity(ent) {
-                ent_commands.insert(Collectible);
+                // Collectibles are picked up on contact, not crashed into.
+                ent_commands.insert(Collectible).remove::<ImpactVelocity>();
             }
         }
     }
@@ -101,12 +66,7 @@ pub fn update_collectible_material_color(
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
     for (ent, asteroid) in asteroid_query.iter_mut() {
-        let color = match asteroid.primary_composition() {
-            AsteroidMaterial::Iron => GRAY,
-            AsteroidMaterial::Silver => SILVER,
-            AsteroidMaterial::Gold => GOLD,
-            _ => LIMEGREEN,
-        };
+        let color = asteroid.primary_composition().color();
 
         commands.entity(ent).insert(MeshMaterial2d(
             materials.add(ColorMaterial::from_color(color)),
@@ -114,9 +74,11 @@ pub fn update_collectible_material_color(
     }
 }
 
+/// Despawns fragments and ore chunks left behind by the player.
+/// Asteroids belonging to a field are unloaded together with their chunk instead.
 pub fn despawn_far_asteroids(
     mut commands: Commands,
-    asteroid_query: Query<(Entity, &mut Asteroid, &mut Transform), With<Asteroid>>,
+    asteroid_query: Query<(Entity, &mut Asteroid, &mut Transform), Without<ChunkOrigin>>,
     player_query: Query<(&Player, &Transform), (With<Player>, Without<Asteroid>)>,
 ) {
     const DESPAWN_DISTANCE: f32 = 1000.0 * PIXELS_PER_METER as f32;
@@ -134,56 +96,80 @@ pub fn despawn_far_asteroids(
 pub fn handle_collectible_collision_event(
     mut commands: Commands,
     collisions: Res<Collisions>,
-    asteroid_query: Query<(Entity, &Asteroid, &Mass), With<Collectible>>,
+    asteroid_query: Query<
+        (Entity, &Asteroid, &Mass, Option<&Inventory>),
+        (With<Collectible>, Without<Player>),
+    >,
     mut player_query: Query<(Entity, &mut Inventory), With<Player>>,
     mut inventory_full_notification: ResMut<InventoryFullNotificationTimer>,
+    item_registry: Res<ItemRegistry>,
 ) {
     let (player_ent, mut inventory) = player_query.single_mut();
 
-    for (asteroid_ent, asteroid, mass) in asteroid_query.iter() {
-        for _ in collisions.get(player_ent, asteroid_ent).iter() {
-            for comp in asteroid.composition.percent_composition().iter() {
-                if !inventory.add_to_inventory(&InventoryItem::Material(
-                    *comp.0,
-                    Amount::Weight(OrderedFloat(comp.1 * mass.0)),
-                )) {
-                    inventory_full_notification.0 = Some(Timer::from_seconds(3.0, TimerMode::Once));
-                }
-            }
+    for (asteroid_ent, asteroid, mass, remaining) in asteroid_query.iter() {
+        if collisions.get(player_ent, asteroid_ent).is_none() {
+            continue;
+        }
+
+        // Chunks that were only partly collected keep what's left of them in an inventory.
+        let mut contents = remaining.cloned().unwrap_or_else(|| Inventory {
+            items: asteroid
+                .composition
+                .percent_composition()
+                .iter()
+                .map(|comp| {
+                    InventoryItem::new(
+                        comp.0.item_id(),
+                        Amount::Weight(OrderedFloat(comp.1 * mass.0)),
+                    )
+                })
+                .filter(|item| !item.amount().is_zero())
+                .collect(),
+            capacity: Capacity {
+                maximum: OrderedFloat(mass.0),
+            },
+            slots: None,
+        });
+
+        contents.transfer_all(&mut inventory, &item_registry);
 
-            // FIXME: will despawn even if there's no room in inventory to collect.
+        if contents.items.is_empty() {
             commands.entity(asteroid_ent).despawn_recursive();
+        } else {
+            inventory_full_notification.0 = Some(Timer::from_seconds(3.0, TimerMode::Once));
+            commands.entity(asteroid_ent).insert(contents);
         }
     }
 }
 
-pub fn handle_asteroid_collision_event(
-    commands: Commands,
-    collisions: Res<Collisions>,
-    mut asteroid_query: Query<(Entity, &Asteroid, &Mass), Without<Collectible>>,
-    mut player_query: Query<(Entity, &mut Player), With<Player>>,
-    // mut player_damage_particle_query: Query<(Entity, &ShipDamageParticleSystem, &mut Transform)>,
-    mut damage_events: EventWriter<DamageEvent>,
+/// Shatters asteroids hit hard enough by another body, cracking them along the line of the impact.
+pub fn split_asteroids_on_impact(
+    mut commands: Commands,
+    mut impact_events: EventReader<ImpactEvent>,
+    asteroid_query: Query<(&Asteroid, &Mass, &GlobalTransform)>,
+    transform_query: Query<&GlobalTransform>,
 ) {
-    let (player_ent, player) = player_query.single_mut();
-
-    // let (damage_particles_ent, _, mut damage_particles_t) =
-    //     player_damage_particle_query.single_mut();
-    // commands.entity(damage_particles_ent).remove::<Playing>();
-
-    for (asteroid_entity, asteroid, mass) in asteroid_query.iter_mut() {
-        if let Some(collision) = collisions.get(player_ent, asteroid_entity) {
-            let damage = -collision.manifolds[0].contacts[0].penetration;
+    for impact in impact_events.read() {
+        let Ok((asteroid, mass, g_transform)) = asteroid_query.get(impact.entity) else {
+            continue;
+        };
+        if asteroid.size.smaller().is_none()
+            || impact.energy / mass.0 < SPLIT_IMPACT_ENERGY_PER_MASS
+        {
+            continue;
+        }
+        let Ok(other_g_transform) = transform_query.get(impact.other) else {
+            continue;
+        };
 
-            damage_events.send(DamageEvent {
-                entity: player_ent,
-                damage: damage as f32,
-            });
+        let position = g_transform.translation().truncate();
+        let normal = (position - other_g_transform.translation().truncate()).normalize_or_zero();
 
-            // damage_particles_t.translation =
-            //     (collision.manifolds[0].contacts[0].point1.as_vec2() * crate::PIXELS_PER_METER as f32).extend(999.0);
-            // commands.entity(damage_particles_ent).insert(Playing);
-        }
+        commands.entity(impact.entity).insert(LastImpact {
+            point: position - normal * asteroid.radius,
+            normal,
+        });
+        commands.trigger(SplitAsteroidEvent(impact.entity));
     }
 }
 
@@ -209,63 +195,145 @@ pub fn ablate_asteroids_events(
     trigger: Trigger<AblateEvent>,
     mut commands: Commands,
     mut asteroids_query: Query<
-        (Entity, &mut Asteroid, &mut Health, &GlobalTransform),
+        (
+            Entity,
+            &mut Asteroid,
+            &mut Ablation,
+            &mut Collider,
+            &GlobalTransform,
+            &LinearVelocity,
+            Option<&AngularVelocity>,
+            Option<&mut OreBudget>,
+        ),
         With<Asteroid>,
     >,
     mut damage_indicator_events: EventWriter<DamageIndicatorEvent>,
+    mut rng: ResMut<SeededRng>,
 ) {
     let ablate_event = trigger.event();
-    let mut rng = rand::thread_rng();
     // let split_angle = rng.gen_range(0.0..PI / 4.0); TODO: Might keep splititng asteroids
 
-    if let Ok((ent, asteroid_to_ablate, mut asteroid_health, _g_trans)) =
-        asteroids_query.get_mut(ablate_event.0)
+    if let Ok((
+        ent,
+        mut asteroid_to_ablate,
+        mut ablation,
+        mut collider,
+        g_trans,
+        linear_velocity,
+        angular_velocity,
+        ore_budget,
+    )) = asteroids_query.get_mut(ablate_event.0)
     {
-        let damaged_health = asteroid_health.current() - LASER_DAMAGE;
-        asteroid_health.set_current(damaged_health);
+        commands.entity(ent).insert(LastImpact {
+            point: ablate_event.1,
+            normal: ablate_event.2,
+        });
 
-        if damaged_health < 0.0 {
-            commands.entity(ent).despawn_recursive();
+        // Once enough laser energy built up, blast a crater as large as the energy into the
+        // surface where the laser hit. Pieces the crater cuts off drift away as fragments.
+        ablation.0 += ablate_event.3;
+        if ablation.0 >= CRATER_ENERGY {
+            let local_point = g_trans
+                .affine()
+                .inverse()
+                .transform_point3(ablate_event.1.extend(0.0))
+                .truncate();
+            let crater_radius = (ablation.0 * CRATER_AREA_PER_ENERGY / PI)
+                .sqrt()
+                .min(asteroid_to_ablate.radius * MAX_CRATER_RADIUS_FRACTION);
+            ablation.0 = 0.0;
+
+            if let Some(fragments) = asteroid_to_ablate.carve_crater(local_point, crater_radius) {
+                *collider = asteroid_collider(&asteroid_to_ablate);
+
+                let transform = g_trans.compute_transform();
+                let angular_velocity = angular_velocity.copied().unwrap_or(AngularVelocity::ZERO);
+
+                for (fragment, offset) in fragments {
+                    let fragment_transform =
+                        transform.with_translation(transform.transform_point(offset.extend(0.0)));
+                    let collider = asteroid_collider(&fragment);
+
+                    spawn_asteroid(
+                        &mut commands,
+                        fragment,
+                        collider,
+                        fragment_transform,
+                        *linear_velocity,
+                        angular_velocity,
+                    );
+                }
+            }
         }
 
-        let n: u8 = rng.gen();
-        if n > 25 {
+        let Some(mut ore_budget) = ore_budget else {
             return;
-        }
-
-        // Send Damage Indicator Event
-        let translation = Transform {
-            translation: (ablate_event.1 + ablate_event.2.normalize() * 100.0).extend(999.0),
-            ..default()
         };
 
-        damage_indicator_events.send(DamageIndicatorEvent {
-            damage: 1.0,
-            traslation: translation,
-        });
+        // The longer and harder the laser works an asteroid, the more ore comes loose,
+        // until the asteroid's ore budget runs out.
+        let loosened = ore_budget.expose(ablate_event.3, asteroid_to_ablate.size.ore_yield());
+        let normal = ablate_event.2.normalize_or_zero();
+
+        for _ in 0..loosened {
+            // Ore knocked loose is made of what the asteroid is made of.
+            let composition = asteroid_to_ablate
+                .composition
+                .jitter(rng.stream(RngStream::AsteroidComposition));
+            let ore_chunk = Asteroid::new_with(
+                AsteroidSize::OreChunk,
+                composition,
+                rng.stream(RngStream::AsteroidShapes),
+            );
+
+            if !ore_budget.take(Asteroid::polygon_area(&ore_chunk.polygon.vertices).abs()) {
+                break;
+            }
 
-        // TODO: The new comp distance shouldn't be constant it should update based on player distance from base
-        let asteroid = Asteroid::new_with(
-            AsteroidSize::OreChunk.radius(),
-            AsteroidComposition::new_with_distance(100.0),
-        );
+            // Send Damage Indicator Event
+            let translation = Transform {
+                translation: (ablate_event.1 + normal * 100.0).extend(999.0),
+                ..default()
+            };
 
-        commands.trigger(SpawnAsteroidEvent(
-            asteroid.clone(),
-            Transform::from_translation(ablate_event.1.extend(0.0)),
-            LinearVelocity(ablate_event.2.as_dvec2()),
-        ));
+            damage_indicator_events.send(DamageIndicatorEvent {
+                damage: 1.0,
+                traslation: translation,
+            });
+
+            // Thrown off the surface where the laser hit, on top of the asteroid's own velocity.
+            let position = ablate_event.1 + normal * AsteroidSize::OreChunk.radius();
+            let velocity = linear_velocity.0 + (normal * ORE_CHUNK_EJECT_SPEED).as_dvec2();
 
+            commands.trigger(SpawnAsteroidEvent(
+                ore_chunk,
+                Transform::from_translation(position.extend(0.0)),
+                LinearVelocity(velocity),
+            ));
+        }
+    }
+}
+
+/// Asteroids worn down to nothing crumble away.
+pub fn destroy_killed_asteroids(
+    mut commands: Commands,
+    mut killed_events: EventReader<KilledEvent>,
+    asteroid_query: Query<(), With<Asteroid>>,
+) {
+    for evt in killed_events.read() {
+        if asteroid_query.contains(evt.entity) {
+            commands.entity(evt.entity).despawn_recursive();
+        }
     }
 }
 
 pub fn split_asteroids_over_split_ratio(
+    mut commands: Commands,
     mut asteroid_query: Query<(Entity, &mut Asteroid, &Health, &Splittable)>,
-    mut split_astroid_events: EventWriter<SplitAsteroidEvent>,
 ) {
     for (ent, asteroid, asteroid_health, split) in asteroid_query.iter_mut() {
         if asteroid_health.current_percent() < split.0 {
-            split_astroid_events.send(SplitAsteroidEvent(ent));
+            commands.trigger(SplitAsteroidEvent(ent));
         }
     }
 }
@@ -273,32 +341,119 @@ pub fn split_asteroids_over_split_ratio(
 pub fn split_asteroid_events(
     trigger: Trigger<SplitAsteroidEvent>,
     mut commands: Commands,
-    mut asteroid_q: Query<(&Asteroid, &Transform, &LinearVelocity)>,
+    asteroid_q: Query<(
+        &Asteroid,
+        &Transform,
+        &LinearVelocity,
+        Option<&AngularVelocity>,
+        Option<&LastImpact>,
+        Option<&OreBudget>,
+    )>,
+    mut rng: ResMut<SeededRng>,
 ) {
     let evt = trigger.event();
     let asteroid_ent = evt.0;
-    if let Ok((asteroid, transform, linear_velocity)) = asteroid_q.get_mut(asteroid_ent) {
-        let right_velocity = Vec2::ZERO;
-        let left_velocity = Vec2::ZERO;
-
-        let half_radius = asteroid.radius / 2.0;
-
-        let left_asteroid = Asteroid::new_with(half_radius, asteroid.composition.jitter());
-        let right_asteroid = Asteroid::new_with(half_radius, asteroid.composition.jitter());
+    let Ok((asteroid, transform, linear_velocity, angular_velocity, last_impact, ore_budget)) =
+        asteroid_q.get(asteroid_ent)
+    else {
+        return;
+    };
+    let Some(smaller) = asteroid.size.smaller() else {
+        return;
+    };
+
+    // Crack straight in from where the laser hit, or through the center if it was never hit.
+    let to_local = transform.compute_affine().inverse();
+    let (mut point, direction) = match last_impact {
+        Some(impact) => (
+            to_local
+                .transform_point3(impact.point.extend(0.0))
+                .truncate(),
+            to_local
+                .transform_vector3(impact.normal.extend(0.0))
+                .truncate(),
+        ),
+        None => (
+            Vec2::ZERO,
+            Vec2::from_angle(rng.stream(RngStream::SplitRatios).gen_range(0.0..TAU)),
+        ),
+    };
+
+    let mut pieces = asteroid.fracture(point, direction);
+    if pieces.len() < 2 {
+        point = Vec2::ZERO;
+        pieces = asteroid.fracture(point, direction);
+    }
+    if pieces.len() < 2 {
+        return;
+    }
 
-        commands.trigger(SpawnAsteroidEvent(
-            left_asteroid,
-            *transform,
-            LinearVelocity::ZERO,
-        ));
+    // Which side of the crack each piece is on. The centroid of a concave piece can lie
+    // across the crack, so go by the vertex farthest from it.
+    let crack_normal = direction.normalize_or(Vec2::X).perp();
+    let sides: Vec<f32> = pieces
+        .iter()
+        .map(|(piece, offset)| {
+            piece
+                .polygon
+                .vertices
+                .iter()
+                .map(|vertex| (*vertex + *offset - point).dot(crack_normal))
+                .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+                .unwrap_or_default()
+                .signum()
+        })
+        .collect();
+
+    // Equal and opposite impulses push the two sides apart at FRACTURE_SEPARATION_SPEED
+    // without changing the total momentum. Mass is proportional to area.
+    let side_mass = |side: f32| -> f32 {
+        pieces
+            .iter()
+            .zip(sides.iter())
+            .filter(|(_, piece_side)| **piece_side == side)
+            .map(|((piece, _), _)| Asteroid::polygon_area(&piece.polygon.vertices))
+            .sum()
+    };
+    let (front_mass, back_mass) = (side_mass(1.0), side_mass(-1.0));
+    let impulse = FRACTURE_SEPARATION_SPEED * front_mass * back_mass / (front_mass + back_mass);
+    let angular_velocity = angular_velocity.copied().unwrap_or(AngularVelocity::ZERO);
+    let total_mass = front_mass + back_mass;
+
+    commands.entity(asteroid_ent).despawn_recursive();
+
+    for ((mut piece, offset), side) in pieces.into_iter().zip(sides) {
+        piece.size = smaller;
+
+        let mass = if side > 0.0 { front_mass } else { back_mass };
+        let separation = transform.rotation * (crack_normal * side * impulse / mass).extend(0.0);
+
+        // Each piece keeps the velocity its part of the asteroid had, including from the spin.
+        let arm = (transform.rotation * offset.extend(0.0)).truncate();
+        let spin = arm.perp() * angular_velocity.0 as f32;
+
+        let piece_velocity = linear_velocity.0 + (spin + separation.truncate()).as_dvec2();
+        let piece_transform =
+            transform.with_translation(transform.transform_point(offset.extend(0.0)));
+
+        // The pieces share what ore was left in the asteroid, by mass.
+        let piece_ore_budget = ore_budget.map(|budget| {
+            budget.share(Asteroid::polygon_area(&piece.polygon.vertices) / total_mass)
+        });
 
-        commands.trigger(SpawnAsteroidEvent(
-            right_asteroid,
-            *transform,
-            LinearVelocity::ZERO,
-        ));
+        let collider = asteroid_collider(&piece);
+        let piece_ent = spawn_asteroid(
+            &mut commands,
+            piece,
+            collider,
+            piece_transform,
+            LinearVelocity(piece_velocity),
+            angular_velocity,
+        );
 
-        commands.entity(asteroid_ent).despawn_recursive();
+        if let Some(piece_ore_budget) = piece_ore_budget {
+            commands.entity(piece_ent).insert(piece_ore_budget);
+        }
     }
 }
 
@@ -310,52 +465,86 @@ pub fn handle_spawn_asteroid_events(
     meshes: ResMut<Assets<Mesh>>,
     materials: ResMut<Assets<ColorMaterial>>,
 ) {
-
     let evt = trigger.event();
 
     let asteroid = evt.0.clone();
     let target_transform = evt.1;
     let linear_velocity = evt.2;
-    let collider = Collider::convex_hull(
-        asteroid
-            .polygon()
-            .vertices
-            .iter()
-            .map(|point| Vector {
-                x: point.x as f64,
-                y: point.y as f64,
-            })
-            .collect(),
-    )
-    .unwrap();
-    let health_pool = collider.mass_properties(1.0).mass; // Set Healthpool to mass?
-
-    let mut rng = rand::thread_rng();
-    let splittable = Splittable(rng.gen_range(0.4..0.8));
-
-    if let Some(transform) =
-        find_free_space(&spatial, &query, target_transform, &collider, 0.1, 10)
+    let collider = asteroid_collider(&asteroid);
+
+    if let Some(transform) = find_free_space(&spatial, &query, target_transform, &collider, 0.1, 10)
     {
-        commands
-            .spawn(asteroid.clone())
-            .insert((
-                RigidBody::Dynamic,
-                collider,
-                linear_velocity,
-                splittable,
-                Name::new("Asteroid"),
-                // Mesh2d(meshes.add(BoxedPolyline2d::new(asteroid.polygon().vertices)).into()),
-                // MeshMaterial2d(materials.add(ColorMaterial::from_color(DARK_GRAY))),
-                transform,
-                Health::with_maximum(Asteroid::polygon_area(
-                    asteroid.polygon().vertices.iter().as_slice(),
-                )),
-            ));
+        spawn_asteroid(
+            &mut commands,
+            asteroid,
+            collider,
+            transform,
+            linear_velocity,
+            AngularVelocity::ZERO,
+        );
+    }
+}
 
+/// Builds a convex hull collider for convex asteroids, and decomposes concave ones
+/// into a compound of convex parts.
+pub fn asteroid_collider(asteroid: &Asteroid) -> Collider {
+    let vertices: Vec<Vector> = asteroid
+        .polygon()
+        .vertices
+        .iter()
+        .map(|point| Vector {
+            x: point.x as f64,
+            y: point.y as f64,
+        })
+        .collect();
+
+    if asteroid.is_convex() {
+        return Collider::convex_hull(vertices).unwrap();
     }
+
+    let count = vertices.len() as u32;
+    let indices = (0..count).map(|i| [i, (i + 1) % count]).collect();
+    Collider::convex_decomposition(vertices, indices)
+}
+
+pub fn spawn_asteroid(
+    commands: &mut Commands,
+    asteroid: Asteroid,
+    collider: Collider,
+    transform: Transform,
+    linear_velocity: LinearVelocity,
+    angular_velocity: AngularVelocity,
+) -> Entity {
+    let health = Health::with_maximum(asteroid.max_health());
+    let split_threshold = asteroid.size.split_threshold();
+    let ore_budget = OreBudget::for_asteroid(&asteroid);
+
+    let mut asteroid_commands = commands.spawn(asteroid);
+    asteroid_commands.insert((
+        RigidBody::Dynamic,
+        collider,
+        linear_velocity,
+        angular_velocity,
+        Name::new("Asteroid"),
+        // Mesh2d(meshes.add(BoxedPolyline2d::new(asteroid.polygon().vertices)).into()),
+        // MeshMaterial2d(materials.add(ColorMaterial::from_color(DARK_GRAY))),
+        transform,
+        health,
+        Resistances::asteroid(),
+        Ablation::default(),
+        ore_budget,
+    ));
+
+    if let Some(split_threshold) = split_threshold {
+        asteroid_commands.insert(Splittable(split_threshold));
+    }
+
+    asteroid_commands.id()
 }
 
-fn find_free_space(
+/// Moves the target transform out of any colliders it overlaps, giving up after
+/// `max_iterations` tries.
+pub fn find_free_space(
     spatial: &SpatialQuery,
     query: &Query<(&Collider, &Transform)>,
     target_transform: Transform,
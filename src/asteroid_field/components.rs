@@ -0,0 +1,515 @@
+use bevy::{prelude::*, reflect::TypePath, utils::HashMap};
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::{
+    asteroid::components::{Asteroid, AsteroidComposition, AsteroidMaterial, AsteroidSize},
+    ron_asset::loader::RonAsset,
+    world_seed::resources::WorldSeed,
+};
+
+/// Width and height of a chunk of the asteroid fields.
+pub const ASTEROID_CHUNK_SIZE: f32 = 2000.0;
+/// Chance of a field asteroid having a concave, cratered outline.
+const CONCAVE_ASTEROID_CHANCE: f64 = 0.5;
+/// Largest random deviation from the field's drift, per axis.
+const DRIFT_JITTER: f32 = 10.0;
+/// Octaves of noise shaping an ore vein, each at twice the frequency and half the weight.
+const VEIN_OCTAVES: u32 = 2;
+
+/// The area an asteroid field covers, in world coordinates.
+#[derive(Debug, Clone, Deserialize)]
+pub enum FieldShape {
+    /// A ring around a center, densest halfway between its inner and outer radius.
+    Belt {
+        center: (f32, f32),
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+    /// A disc that thins out towards its edge.
+    Cluster { center: (f32, f32), radius: f32 },
+}
+
+impl FieldShape {
+    /// How dense the field is at a point, from 0.0 outside of it to 1.0 at its heart.
+    pub fn strength_at(&self, point: Vec2) -> f32 {
+        match *self {
+            FieldShape::Belt {
+                center,
+                inner_radius,
+                outer_radius,
+            } => {
+                let distance = point.distance(Vec2::from(center));
+                let middle = (inner_radius + outer_radius) / 2.0;
+                let half_width = (outer_radius - inner_radius) / 2.0;
+
+                (1.0 - (distance - middle).abs() / half_width).max(0.0)
+            }
+            FieldShape::Cluster { center, radius } => {
+                (1.0 - point.distance(Vec2::from(center)) / radius).max(0.0)
+            }
+        }
+    }
+}
+
+/// A named belt or cluster of asteroids as written in the world layout file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AsteroidField {
+    pub name: String,
+    pub shape: FieldShape,
+    /// Asteroids per chunk where the field is densest.
+    pub density: f32,
+    /// Velocity the asteroids of the field drift with, in pixels per second.
+    #[serde(default)]
+    pub drift: (f32, f32),
+    /// Relative weights of the ores the asteroids are made of.
+    pub materials: Vec<(AsteroidMaterial, f32)>,
+    /// Fraction of every asteroid that is plain rock, whatever its ores.
+    #[serde(default)]
+    pub rock: f32,
+    /// Relative weights of the sizes the asteroids spawn with.
+    pub sizes: Vec<(AsteroidSize, f32)>,
+}
+
+impl AsteroidField {
+    pub fn pick_size(&self, rng: &mut impl Rng) -> AsteroidSize {
+        let total: f32 = self.sizes.iter().map(|(_, weight)| weight).sum();
+        let mut roll = rng.gen::<f32>() * total;
+
+        for (size, weight) in self.sizes.iter() {
+            if roll < *weight {
+                return *size;
+            }
+            roll -= weight;
+        }
+
+        self.sizes
+            .last()
+            .map(|(size, _)| *size)
+            .unwrap_or(AsteroidSize::Large)
+    }
+}
+
+/// A band of enriched ore running through world space, following a noise pattern.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OreVein {
+    pub material: AsteroidMaterial,
+    /// Typical width of the vein's features, in pixels.
+    pub scale: f32,
+    /// Noise value from 0.0 to 1.0 above which the ore is enriched. Higher is rarer.
+    pub threshold: f32,
+    /// Weight added to the material at the heart of the vein.
+    pub richness: f32,
+}
+
+impl OreVein {
+    /// How enriched the vein is at a point, from 0.0 outside of it to 1.0 at its heart.
+    pub fn strength_at(&self, point: Vec2, seed: u64) -> f32 {
+        let noise = fractal_noise(point / self.scale, seed);
+
+        ((noise - self.threshold) / (1.0 - self.threshold)).max(0.0)
+    }
+}
+
+/// A material that only turns up in the odd asteroid, wherever it is.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RareMaterial {
+    pub material: AsteroidMaterial,
+    /// Chance of an asteroid containing the material.
+    pub chance: f32,
+    /// Weight added to the material when it does.
+    pub weight: f32,
+}
+
+/// How the materials of asteroids are distributed over the world,
+/// on top of the profile of the field they belong to.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MaterialModel {
+    #[serde(default)]
+    pub veins: Vec<OreVein>,
+    #[serde(default)]
+    pub rare: Vec<RareMaterial>,
+}
+
+impl MaterialModel {
+    /// Returns the composition of an asteroid of a field at a position in the world.
+    /// The field's rock fraction is kept as is, the ores share the rest by weight.
+    pub fn composition(
+        &self,
+        field: &AsteroidField,
+        position: Vec2,
+        seed: WorldSeed,
+        rng: &mut impl Rng,
+    ) -> AsteroidComposition {
+        let mut ores: HashMap<AsteroidMaterial, f32> = HashMap::new();
+        for (material, weight) in field.materials.iter() {
+            *ores.entry(*material).or_default() += weight;
+        }
+
+        for (index, vein) in self.veins.iter().enumerate() {
+            let strength = vein.strength_at(position, vein_seed(seed, index));
+            *ores.entry(vein.material).or_default() += vein.richness * strength;
+        }
+
+        for rare in self.rare.iter() {
+            if rng.gen::<f32>() < rare.chance {
+                *ores.entry(rare.material).or_default() += rare.weight;
+            }
+        }
+
+        let total: f32 = ores.values().sum();
+        if total <= 0.0 {
+            return AsteroidComposition::from_weights([(AsteroidMaterial::Rock, 1.0)]);
+        }
+
+        let rock = field.rock.clamp(0.0, 1.0);
+        let mut composition: HashMap<AsteroidMaterial, f32> = ores
+            .into_iter()
+            .map(|(material, weight)| (material, weight / total * (1.0 - rock)))
+            .collect();
+        *composition.entry(AsteroidMaterial::Rock).or_default() += rock;
+
+        AsteroidComposition::from_weights(composition)
+    }
+}
+
+/// Each vein gets its own noise, so veins of different ores don't overlap exactly.
+fn vein_seed(seed: WorldSeed, index: usize) -> u64 {
+    seed.0 ^ (index as u64 + 1).wrapping_mul(0xD6E8_FEB8_6659_FD93)
+}
+
+/// Smooth value noise from 0.0 to 1.0, interpolating random values on a grid of unit cells.
+fn value_noise(point: Vec2, seed: u64) -> f32 {
+    let cell = point.floor();
+    let t = point - cell;
+    let t = t * t * (Vec2::splat(3.0) - 2.0 * t);
+
+    let corner = |dx: i64, dy: i64| lattice_value(cell.x as i64 + dx, cell.y as i64 + dy, seed);
+    let bottom = corner(0, 0) + (corner(1, 0) - corner(0, 0)) * t.x;
+    let top = corner(0, 1) + (corner(1, 1) - corner(0, 1)) * t.x;
+
+    bottom + (top - bottom) * t.y
+}
+
+/// Value noise with finer octaves layered on top, still from 0.0 to 1.0.
+fn fractal_noise(point: Vec2, seed: u64) -> f32 {
+    let (total, weights) = (0..VEIN_OCTAVES).fold((0.0, 0.0), |(total, weights), octave| {
+        let weight = 0.5f32.powi(octave as i32);
+        let frequency = 2.0f32.powi(octave as i32);
+        let noise = value_noise(point * frequency, seed.wrapping_add(octave as u64));
+
+        (total + noise * weight, weights + weight)
+    });
+
+    total / weights
+}
+
+/// A random value from 0.0 to 1.0 for a grid point, hashed with the splitmix64 finalizer.
+fn lattice_value(x: i64, y: i64, seed: u64) -> f32 {
+    let mut hash = seed
+        ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// An asteroid a chunk generates, before it is spawned.
+#[derive(Clone)]
+pub struct PlannedAsteroid {
+    pub origin: ChunkOrigin,
+    /// Index of the field it belongs to in the WorldLayout.
+    pub field: usize,
+    pub asteroid: Asteroid,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct WorldLayout {
+    pub fields: Vec<AsteroidField>,
+    #[serde(default)]
+    pub material_model: MaterialModel,
+}
+
+impl RonAsset for WorldLayout {
+    const EXTENSIONS: &'static [&'static str] = &["layout.ron"];
+}
+
+impl WorldLayout {
+    /// Returns the chunk a point in the world falls into.
+    pub fn chunk_at(point: Vec2) -> IVec2 {
+        (point / ASTEROID_CHUNK_SIZE).floor().as_ivec2()
+    }
+
+    /// Generates the asteroids of a chunk. Every field rolls the same number of candidates
+    /// for a chunk, so an asteroid keeps its index however many of the others were mined.
+    pub fn generate_chunk(
+        &self,
+        chunk: IVec2,
+        seed: WorldSeed,
+        rng: &mut impl Rng,
+    ) -> Vec<PlannedAsteroid> {
+        let chunk_min = chunk.as_vec2() * ASTEROID_CHUNK_SIZE;
+        let mut planned: Vec<PlannedAsteroid> = Vec::new();
+        let mut index = 0;
+
+        for (field_index, field) in self.fields.iter().enumerate() {
+            let candidates = field.density.ceil() as usize;
+
+            for _ in 0..candidates {
+                let origin = ChunkOrigin { chunk, index };
+                index += 1;
+
+                let position = chunk_min + Vec2::new(rng.gen(), rng.gen()) * ASTEROID_CHUNK_SIZE;
+                let chance = field.shape.strength_at(position) * field.density / candidates as f32;
+                if rng.gen::<f32>() >= chance {
+                    continue;
+                }
+
+                let size = field.pick_size(rng);
+                let overlaps = planned.iter().any(|other| {
+                    other.position.distance(position) < other.asteroid.size.radius() + size.radius()
+                });
+                if overlaps {
+                    continue;
+                }
+
+                let composition = self
+                    .material_model
+                    .composition(field, position, seed, rng)
+                    .jitter(rng);
+                let asteroid = if rng.gen_bool(CONCAVE_ASTEROID_CHANCE) {
+                    Asteroid::new_concave(size, composition, rng)
+                } else {
+                    Asteroid::new_with(size, composition, rng)
+                };
+                let jitter = Vec2::new(
+                    rng.gen_range(-DRIFT_JITTER..DRIFT_JITTER),
+                    rng.gen_range(-DRIFT_JITTER..DRIFT_JITTER),
+                );
+
+                planned.push(PlannedAsteroid {
+                    origin,
+                    field: field_index,
+                    asteroid,
+                    position,
+                    velocity: Vec2::from(field.drift) + jitter,
+                });
+            }
+        }
+
+        planned
+    }
+}
+
+/// The chunk an asteroid was generated in, and its index among that chunk's asteroids.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkOrigin {
+    pub chunk: IVec2,
+    pub index: usize,
+}
+
+/// Marks an asteroid despawned because its chunk was unloaded, rather than mined.
+#[derive(Component)]
+pub struct Unloaded;
+
+#[cfg(test)]
+fn test_layout() -> WorldLayout {
+    WorldLayout {
+        fields: vec![AsteroidField {
+            name: String::from("Test Cluster"),
+            shape: FieldShape::Cluster {
+                center: (0.0, 0.0),
+                radius: 10000.0,
+            },
+            density: 8.0,
+            drift: (10.0, 0.0),
+            materials: vec![
+                (AsteroidMaterial::Iron, 0.9),
+                (AsteroidMaterial::Silver, 0.1),
+            ],
+            rock: 0.2,
+            sizes: vec![(AsteroidSize::Medium, 1.0), (AsteroidSize::Small, 1.0)],
+        }],
+        material_model: MaterialModel::default(),
+    }
+}
+
+#[test]
+fn test_chunks_generate_the_same_asteroids() {
+    use crate::world_seed::resources::{RngStream, SeededRng, WorldSeed};
+
+    let layout = test_layout();
+    let rng = SeededRng::new(WorldSeed(42));
+    let generate = |chunk| {
+        layout
+            .generate_chunk(
+                chunk,
+                rng.seed(),
+                &mut rng.for_chunk(RngStream::AsteroidFields, chunk),
+            )
+            .into_iter()
+            .map(|planned| (planned.origin, planned.position))
+            .collect::<Vec<_>>()
+    };
+
+    let chunk = IVec2::new(0, -1);
+    let first = generate(chunk);
+    assert!(!first.is_empty());
+
+    // Generating other chunks in between doesn't change what this one generates.
+    generate(IVec2::new(1, 1));
+    assert_eq!(first, generate(chunk));
+
+    // Chunks outside of every field stay empty.
+    assert!(generate(IVec2::new(50, 50)).is_empty());
+}
+
+#[cfg(test)]
+fn test_field(materials: Vec<(AsteroidMaterial, f32)>, rock: f32) -> AsteroidField {
+    AsteroidField {
+        materials,
+        rock,
+        ..test_layout().fields[0].clone()
+    }
+}
+
+#[test]
+fn test_value_noise_is_smooth_and_centered() {
+    let points: Vec<Vec2> = (0..100)
+        .flat_map(|i| (0..100).map(move |j| Vec2::new(i as f32 * 0.37, j as f32 * 0.53)))
+        .collect();
+
+    let values: Vec<f32> = points.iter().map(|point| value_noise(*point, 3)).collect();
+    assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    assert!((0.45..0.55).contains(&mean), "mean noise {}", mean);
+
+    for point in points.iter() {
+        let step = value_noise(*point + Vec2::new(0.01, 0.01), 3) - value_noise(*point, 3);
+        assert!(step.abs() < 0.05);
+    }
+}
+
+#[test]
+fn test_composition_keeps_rock_fraction() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let model = MaterialModel {
+        veins: vec![OreVein {
+            material: AsteroidMaterial::Gold,
+            scale: 500.0,
+            threshold: 0.5,
+            richness: 2.0,
+        }],
+        rare: vec![],
+    };
+    let field = test_field(vec![(AsteroidMaterial::Iron, 1.0)], 0.3);
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+    for i in 0..200 {
+        let position = Vec2::new(i as f32 * 97.0, i as f32 * -61.0);
+        let composition = model
+            .composition(&field, position, WorldSeed(9), &mut rng)
+            .percent_composition();
+
+        assert!((composition[&AsteroidMaterial::Rock] - 0.3).abs() < 1e-5);
+        let total: f32 = composition.values().sum();
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_profile_sets_the_mean_composition() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let model = MaterialModel::default();
+    let field = test_field(
+        vec![
+            (AsteroidMaterial::Iron, 0.7),
+            (AsteroidMaterial::Silver, 0.3),
+        ],
+        0.2,
+    );
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+
+    const SAMPLES: usize = 2000;
+    let mut iron = 0.0;
+    for _ in 0..SAMPLES {
+        let composition = model
+            .composition(&field, Vec2::ZERO, WorldSeed(0), &mut rng)
+            .jitter(&mut rng)
+            .percent_composition();
+        iron += composition[&AsteroidMaterial::Iron];
+    }
+
+    // 0.7 of the 0.8 left over by the rock, give or take the bias of the jitter.
+    let mean = iron / SAMPLES as f32;
+    assert!((mean - 0.56).abs() < 0.03, "mean iron {}", mean);
+}
+
+#[test]
+fn test_rare_materials_turn_up_at_their_chance() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let model = MaterialModel {
+        veins: vec![],
+        rare: vec![RareMaterial {
+            material: AsteroidMaterial::Gold,
+            chance: 0.05,
+            weight: 0.5,
+        }],
+    };
+    let field = test_field(vec![(AsteroidMaterial::Iron, 1.0)], 0.0);
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+
+    const SAMPLES: usize = 10000;
+    let with_gold = (0..SAMPLES)
+        .filter(|_| {
+            model
+                .composition(&field, Vec2::ZERO, WorldSeed(0), &mut rng)
+                .percent_composition()
+                .get(&AsteroidMaterial::Gold)
+                .is_some_and(|fraction| *fraction > 0.0)
+        })
+        .count();
+
+    // Binomial with a mean of 500 and a standard deviation of about 22, allow 4 of them.
+    assert!((413..=587).contains(&with_gold), "{} with gold", with_gold);
+}
+
+#[test]
+fn test_veins_form_coherent_regions() {
+    let vein = OreVein {
+        material: AsteroidMaterial::Silver,
+        scale: 1000.0,
+        threshold: 0.6,
+        richness: 1.0,
+    };
+
+    let points: Vec<Vec2> = (0..150)
+        .flat_map(|i| (0..150).map(move |j| Vec2::new(i as f32, j as f32) * 137.0))
+        .collect();
+    let enriched: Vec<&Vec2> = points
+        .iter()
+        .filter(|point| vein.strength_at(**point, 5) > 0.0)
+        .collect();
+
+    // The vein covers part of the space, not none or all of it.
+    let coverage = enriched.len() as f32 / points.len() as f32;
+    assert!((0.05..0.6).contains(&coverage), "coverage {}", coverage);
+
+    // Close to an enriched point the ore is almost always enriched too.
+    let neighbours = enriched
+        .iter()
+        .filter(|point| vein.strength_at(**point + Vec2::new(10.0, 0.0), 5) > 0.0)
+        .count();
+    assert!(neighbours as f32 / enriched.len() as f32 > 0.8);
+}
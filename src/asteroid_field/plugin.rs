@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::ron_asset::loader::RonAssetLoader;
+
+use super::{
+    components::WorldLayout,
+    resources::AsteroidChunks,
+    systems::{
+        load_world_layout, reload_asteroid_chunks, remember_mined_asteroids, update_asteroid_chunks,
+    },
+};
+
+pub struct AsteroidFieldPlugin;
+
+impl Plugin for AsteroidFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<WorldLayout>()
+            .init_asset_loader::<RonAssetLoader<WorldLayout>>()
+            .init_resource::<AsteroidChunks>()
+            .add_systems(PreStartup, load_world_layout)
+            .add_systems(
+                Update,
+                (reload_asteroid_chunks, update_asteroid_chunks).chain(),
+            )
+            .add_observer(remember_mined_asteroids);
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::asteroid::components::{Asteroid, OreBudget};
+
+use super::components::{ChunkOrigin, WorldLayout};
+
+pub const WORLD_LAYOUT_PATH: &str = "data/core.layout.ron";
+
+#[derive(Resource)]
+pub struct WorldLayoutHandle(pub Handle<WorldLayout>);
+
+/// A field asteroid that was damaged or drifted out of the chunk it was generated in,
+/// kept as it was when its surroundings unloaded.
+#[derive(Debug, Clone)]
+pub struct StoredAsteroid {
+    pub origin: ChunkOrigin,
+    pub name: Name,
+    pub asteroid: Asteroid,
+    pub health: f32,
+    pub ore_budget: Option<OreBudget>,
+    pub transform: Transform,
+    pub velocity: Vec2,
+    pub angular_velocity: f32,
+}
+
+/// The chunks of the asteroid fields currently spawned around the camera,
+/// the asteroids that were mined out of them, and the asteroids waiting
+/// in unloaded chunks to be spawned again as they were left.
+#[derive(Resource, Default, Debug)]
+pub struct AsteroidChunks {
+    pub loaded: HashSet<IVec2>,
+    pub mined: HashSet<ChunkOrigin>,
+    /// Stored asteroids by the chunk they were in when they were unloaded.
+    pub stored: HashMap<IVec2, Vec<StoredAsteroid>>,
+}
+
+impl AsteroidChunks {
+    pub fn is_mined(&self, origin: &ChunkOrigin) -> bool {
+        self.mined.contains(origin)
+    }
+
+    /// True if the asteroid lives on as a stored asteroid, rather than being generated again.
+    pub fn is_stored(&self, origin: &ChunkOrigin) -> bool {
+        self.stored
+            .values()
+            .flatten()
+            .any(|stored| stored.origin == *origin)
+    }
+}
@@ -0,0 +1,217 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::{
+    asteroid::{
+        components::{Asteroid, OreBudget},
+        systems::{asteroid_collider, find_free_space, spawn_asteroid},
+    },
+    camera::components::GameCamera,
+    health::components::Health,
+    world_seed::resources::{RngStream, SeededRng},
+};
+
+use super::{
+    components::{ChunkOrigin, Unloaded, WorldLayout},
+    resources::{AsteroidChunks, StoredAsteroid, WorldLayoutHandle, WORLD_LAYOUT_PATH},
+};
+
+/// Chunks this far from the camera's chunk, in chunks, are generated.
+const CHUNK_LOAD_RADIUS: i32 = 2;
+/// Chunks further than this from the camera's chunk are unloaded.
+/// Larger than the load radius so chunks on the border don't flicker in and out.
+const CHUNK_UNLOAD_RADIUS: i32 = 3;
+
+pub fn load_world_layout(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WorldLayoutHandle(asset_server.load(WORLD_LAYOUT_PATH)));
+}
+
+/// Generates the asteroid field chunks around the camera and unloads the ones it left behind.
+/// Asteroids are unloaded by the chunk they drifted into, not the one they came from. Those
+/// that were damaged or drifted away are stored and come back where they were left, while
+/// asteroids that were mined out of a chunk aren't spawned again.
+#[allow(clippy::too_many_arguments)]
+pub fn update_asteroid_chunks(
+    mut commands: Commands,
+    camera_query: Query<&GlobalTransform, With<GameCamera>>,
+    asteroid_query: Query<(
+        Entity,
+        &ChunkOrigin,
+        &Asteroid,
+        &Transform,
+        &Health,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+        Option<&OreBudget>,
+        Option<&Name>,
+    )>,
+    collider_query: Query<(&Collider, &Transform)>,
+    spatial: SpatialQuery,
+    mut chunks: ResMut<AsteroidChunks>,
+    layouts: Res<Assets<WorldLayout>>,
+    layout_handle: Res<WorldLayoutHandle>,
+    rng: Res<SeededRng>,
+) {
+    let Some(layout) = layouts.get(&layout_handle.0) else {
+        return;
+    };
+    let Ok(camera_gt) = camera_query.get_single() else {
+        return;
+    };
+
+    let center = WorldLayout::chunk_at(camera_gt.translation().truncate());
+    let out_of_range = |chunk: IVec2| (chunk - center).abs().max_element() > CHUNK_UNLOAD_RADIUS;
+
+    chunks.loaded.retain(|chunk| !out_of_range(*chunk));
+
+    for (
+        entity,
+        origin,
+        asteroid,
+        transform,
+        health,
+        velocity,
+        angular_velocity,
+        ore_budget,
+        name,
+    ) in asteroid_query.iter()
+    {
+        let chunk = WorldLayout::chunk_at(transform.translation.truncate());
+        if !out_of_range(chunk) {
+            continue;
+        }
+
+        // Untouched asteroids still in their own chunk are simply generated again.
+        if health.current() < health.maximum() || chunk != origin.chunk {
+            chunks
+                .stored
+                .entry(chunk)
+                .or_default()
+                .push(StoredAsteroid {
+                    origin: *origin,
+                    name: name.cloned().unwrap_or_else(|| Name::new("Asteroid")),
+                    asteroid: asteroid.clone(),
+                    health: health.current(),
+                    ore_budget: ore_budget.copied(),
+                    transform: *transform,
+                    velocity: velocity.map_or(Vec2::ZERO, |velocity| velocity.0.as_vec2()),
+                    angular_velocity: angular_velocity.map_or(0.0, |angular| angular.0 as f32),
+                });
+        }
+
+        commands.entity(entity).insert(Unloaded).despawn_recursive();
+    }
+
+    for i in -CHUNK_LOAD_RADIUS..=CHUNK_LOAD_RADIUS {
+        for j in -CHUNK_LOAD_RADIUS..=CHUNK_LOAD_RADIUS {
+            let chunk = center + IVec2::new(i, j);
+            if !chunks.loaded.insert(chunk) {
+                continue;
+            }
+
+            for stored in chunks.stored.remove(&chunk).unwrap_or_default() {
+                let collider = asteroid_collider(&stored.asteroid);
+                let Some(transform) = find_free_space(
+                    &spatial,
+                    &collider_query,
+                    stored.transform,
+                    &collider,
+                    0.1,
+                    10,
+                ) else {
+                    // Try again the next time the chunk loads.
+                    chunks.stored.entry(chunk).or_default().push(stored);
+                    continue;
+                };
+
+                let mut health = Health::with_maximum(stored.asteroid.max_health());
+                health.set_current(stored.health);
+
+                let entity = spawn_asteroid(
+                    &mut commands,
+                    stored.asteroid,
+                    collider,
+                    transform,
+                    LinearVelocity(stored.velocity.as_dvec2()),
+                    AngularVelocity(stored.angular_velocity as f64),
+                );
+
+                commands
+                    .entity(entity)
+                    .insert((stored.origin, stored.name, health));
+                if let Some(ore_budget) = stored.ore_budget {
+                    commands.entity(entity).insert(ore_budget);
+                }
+            }
+
+            let mut chunk_rng = rng.for_chunk(RngStream::AsteroidFields, chunk);
+
+            for planned in layout.generate_chunk(chunk, rng.seed(), &mut chunk_rng) {
+                if chunks.is_mined(&planned.origin) || chunks.is_stored(&planned.origin) {
+                    continue;
+                }
+
+                let collider = asteroid_collider(&planned.asteroid);
+                let Some(transform) = find_free_space(
+                    &spatial,
+                    &collider_query,
+                    Transform::from_translation(planned.position.extend(0.0)),
+                    &collider,
+                    0.1,
+                    10,
+                ) else {
+                    continue;
+                };
+
+                let name = Name::new(format!("{} Asteroid", layout.fields[planned.field].name));
+                let entity = spawn_asteroid(
+                    &mut commands,
+                    planned.asteroid,
+                    collider,
+                    transform,
+                    LinearVelocity(planned.velocity.as_dvec2()),
+                    AngularVelocity::ZERO,
+                );
+
+                commands.entity(entity).insert((planned.origin, name));
+            }
+        }
+    }
+}
+
+/// Regenerates every chunk when the world layout file changes.
+/// The mined asteroids are forgotten, as their indices no longer match the new layout.
+pub fn reload_asteroid_chunks(
+    mut commands: Commands,
+    mut layout_events: EventReader<AssetEvent<WorldLayout>>,
+    asteroid_query: Query<Entity, With<ChunkOrigin>>,
+    mut chunks: ResMut<AsteroidChunks>,
+) {
+    let modified = layout_events
+        .read()
+        .any(|event| matches!(event, AssetEvent::Modified { .. }));
+
+    if !modified {
+        return;
+    }
+
+    for entity in asteroid_query.iter() {
+        commands.entity(entity).insert(Unloaded).despawn_recursive();
+    }
+
+    chunks.loaded.clear();
+    chunks.mined.clear();
+    chunks.stored.clear();
+}
+
+/// Remembers field asteroids that were mined, split or collected,
+/// so they don't come back when their chunk is generated again.
+pub fn remember_mined_asteroids(
+    trigger: Trigger<OnRemove, ChunkOrigin>,
+    origin_query: Query<&ChunkOrigin, Without<Unloaded>>,
+    mut chunks: ResMut<AsteroidChunks>,
+) {
+    if let Ok(origin) = origin_query.get(trigger.entity()) {
+        chunks.mined.insert(*origin);
+    }
+}
@@ -1,4 +1,76 @@
-use bevy::prelude::Component;
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::{
+    asteroid::components::AsteroidMaterial,
+    upgrades::components::{UpgradeLevel, Upgradeable},
+    PIXELS_PER_METER,
+};
 
 #[derive(Component)]
 pub struct Collectible;
+
+/// A ship system pulling collectibles in range towards the ship, draining the battery while it does.
+#[derive(Component, Debug, Clone)]
+pub struct TractorBeam {
+    _range: f32,
+    _strength: f32,
+    /// Battery charge drained per second while pulling.
+    pub drain: f32,
+    /// Collectibles mostly made of these materials are left alone.
+    pub ignored: HashSet<AsteroidMaterial>,
+    _upgrade_level: UpgradeLevel,
+}
+
+impl Default for TractorBeam {
+    fn default() -> Self {
+        Self {
+            _range: 30.0 * PIXELS_PER_METER as f32,
+            _strength: 300.0 * PIXELS_PER_METER as f32,
+            drain: 2.0,
+            ignored: [AsteroidMaterial::Rock].into_iter().collect(),
+            _upgrade_level: UpgradeLevel::Level0,
+        }
+    }
+}
+
+impl TractorBeam {
+    /// Distance in pixels the beam reaches.
+    pub fn range(&self) -> f32 {
+        self._range * self.upgrade_effect()
+    }
+
+    /// Acceleration in pixels per second squared right next to the ship,
+    /// fading out towards the edge of the range.
+    pub fn strength(&self) -> f32 {
+        self._strength * self.upgrade_effect()
+    }
+
+    pub fn pulls(&self, material: AsteroidMaterial) -> bool {
+        !self.ignored.contains(&material)
+    }
+
+    pub fn set_pulls(&mut self, material: AsteroidMaterial, pulls: bool) {
+        if pulls {
+            self.ignored.remove(&material);
+        } else {
+            self.ignored.insert(material);
+        }
+    }
+}
+
+impl Upgradeable for TractorBeam {
+    fn set_upgrade_level(&mut self, upgrade_level: UpgradeLevel) {
+        self._upgrade_level = upgrade_level;
+    }
+
+    fn upgrade_effect(&self) -> f32 {
+        match self._upgrade_level {
+            UpgradeLevel::Level0 => 1.0,
+            UpgradeLevel::Level1 => 1.25,
+            UpgradeLevel::Level2 => 1.5,
+            UpgradeLevel::Level3 => 2.0,
+            UpgradeLevel::Level4 => 2.5,
+            UpgradeLevel::MaxLevel => 3.0,
+        }
+    }
+}
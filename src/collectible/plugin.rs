@@ -1,13 +1,17 @@
 use bevy::prelude::*;
-use bevy_tweening::TweeningPlugin;
 
-use super::systems::gravitate_collectibles_towards_player_ship;
+use super::systems::{apply_tractor_beam_upgrades, pull_collectibles_with_tractor_beam};
 
 pub struct CollectiblesPlugin;
 
 impl Plugin for CollectiblesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(TweeningPlugin)
-            .add_systems(Update, gravitate_collectibles_towards_player_ship);
+        app.add_systems(
+            Update,
+            (
+                apply_tractor_beam_upgrades,
+                pull_collectibles_with_tractor_beam.after(apply_tractor_beam_upgrades),
+            ),
+        );
     }
 }
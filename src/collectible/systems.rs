@@ -1,34 +1,83 @@
 use avian2d::prelude::LinearVelocity;
 use bevy::prelude::*;
+use ordered_float::OrderedFloat;
 
-use super::components::Collectible;
+use super::components::{Collectible, TractorBeam};
 
-use crate::player::components::Player;
+use crate::{
+    asteroid::components::Asteroid,
+    battery::{components::Battery, events::DrainBatteryEvent},
+    inventory::components::{Inventory, InventoryItem},
+    items::{resources::ItemRegistry, Amount},
+    player::components::Player,
+    upgrades::components::{UpgradeType, Upgradeable, UpgradesComponent},
+};
 
-pub fn gravitate_collectibles_towards_player_ship(
-    mut collectible_query: Query<(Entity, &Collectible, &Transform, &mut LinearVelocity)>,
-    player_query: Query<(Entity, &Player, &Transform), With<Player>>,
+/// Pulls collectibles in range towards the ship, skipping ignored materials and
+/// the ones the cargo hold has no room left for.
+pub fn pull_collectibles_with_tractor_beam(
+    mut commands: Commands,
+    mut collectible_query: Query<(&Asteroid, &Transform, &mut LinearVelocity), With<Collectible>>,
+    player_query: Query<(Entity, &TractorBeam, &Battery, &Inventory, &Transform), With<Player>>,
+    item_registry: Res<ItemRegistry>,
+    time: Res<Time>,
 ) {
-    const MAX_GRAVITATION_DISTANCE: f64 = 30.0 * crate::PIXELS_PER_METER;
-    let (_player_ent, _player, player_transform) = player_query.single();
-
-    for (_ent, _collectible, collectible_tranform, mut velocity) in collectible_query.iter_mut() {
-        let distance_to_player_from_collectible = player_transform
-            .translation
-            .truncate()
-            .distance(collectible_tranform.translation.truncate())
-            as f64;
-        if distance_to_player_from_collectible < MAX_GRAVITATION_DISTANCE {
-            let percent_distance_from_max =
-                distance_to_player_from_collectible / MAX_GRAVITATION_DISTANCE;
-            let direction_to_player_from_collectible = (player_transform.translation.truncate()
-                - collectible_tranform.translation.truncate())
-            .normalize();
-            let gravitation_factor = 1.0 - percent_distance_from_max;
-            velocity.0 += direction_to_player_from_collectible.as_dvec2()
-                * gravitation_factor
-                * 5.0
-                * crate::PIXELS_PER_METER;
+    let Ok((player_ent, tractor_beam, battery, inventory, player_transform)) =
+        player_query.get_single()
+    else {
+        return;
+    };
+
+    if battery.is_empty() {
+        return;
+    }
+
+    let player_position = player_transform.translation.truncate();
+    let mut pulling = false;
+
+    for (asteroid, collectible_transform, mut velocity) in collectible_query.iter_mut() {
+        let offset = player_position - collectible_transform.translation.truncate();
+        let distance = offset.length();
+        if distance >= tractor_beam.range() {
+            continue;
+        }
+
+        let material = asteroid.primary_composition();
+        if !tractor_beam.pulls(material) {
+            continue;
+        }
+
+        let sample = InventoryItem::new(material.item_id(), Amount::Weight(OrderedFloat(1.0)));
+        if inventory.fitting_amount(&sample, &item_registry).is_zero() {
+            continue;
+        }
+
+        let falloff = 1.0 - distance / tractor_beam.range();
+        let acceleration = offset.normalize_or_zero() * tractor_beam.strength() * falloff;
+        velocity.0 += (acceleration * time.delta_secs()).as_dvec2();
+        pulling = true;
+    }
+
+    if pulling {
+        commands.trigger(DrainBatteryEvent {
+            entity: player_ent,
+            drain: tractor_beam.drain * time.delta_secs(),
+        });
+    }
+}
+
+/// Applies the tractor beam upgrade level to the ship's tractor beam.
+pub fn apply_tractor_beam_upgrades(
+    mut player_query: Query<(&UpgradesComponent, &mut TractorBeam), Changed<UpgradesComponent>>,
+) {
+    for (upgrades, mut tractor_beam) in player_query.iter_mut() {
+        let level = upgrades.upgrades.iter().find_map(|upgrade| match upgrade {
+            UpgradeType::TractorBeam(level) => Some(*level),
+            _ => None,
+        });
+
+        if let Some(level) = level {
+            tractor_beam.set_upgrade_level(level);
         }
     }
 }
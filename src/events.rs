@@ -1,5 +0,0 @@
-use crate::recipe::Recipe;
-use bevy::prelude::Event;
-
-#[derive(Event)]
-pub struct CraftEvent(pub Recipe);
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
 use crate::upgrades::components::{UpgradeLevel, Upgradeable};
 
@@ -67,3 +68,176 @@ impl Upgradeable for Health {
         }
     }
 }
+
+/// What kind of harm a source of damage does.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum DamageType {
+    /// Projectiles and other solid hits.
+    #[default]
+    Kinetic,
+    /// Heat, ie. the mining laser.
+    Thermal,
+    Explosive,
+    /// Bodies running into each other.
+    Collision,
+}
+
+/// How much of each type of damage an entity takes, as a multiplier.
+/// Entities without resistances take all damage in full.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Resistances {
+    pub kinetic: f32,
+    pub thermal: f32,
+    pub explosive: f32,
+    pub collision: f32,
+}
+
+impl Default for Resistances {
+    fn default() -> Self {
+        Self {
+            kinetic: 1.0,
+            thermal: 1.0,
+            explosive: 1.0,
+            collision: 1.0,
+        }
+    }
+}
+
+impl Resistances {
+    /// Rock shrugs off bullets but cracks under the mining laser.
+    pub fn asteroid() -> Self {
+        Self {
+            kinetic: 0.25,
+            thermal: 2.0,
+            ..default()
+        }
+    }
+
+    pub fn multiplier(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Kinetic => self.kinetic,
+            DamageType::Thermal => self.thermal,
+            DamageType::Explosive => self.explosive,
+            DamageType::Collision => self.collision,
+        }
+    }
+
+    pub fn damage_taken(&self, damage: f32, damage_type: DamageType) -> f32 {
+        let multiplier = self.multiplier(damage_type);
+        // Immune, even to lethal damage.
+        if multiplier <= 0.0 {
+            return 0.0;
+        }
+
+        damage * multiplier
+    }
+}
+
+/// Absorbs damage before it reaches Health, and recharges once the entity stopped taking hits.
+#[derive(Component, Clone, Debug)]
+pub struct Shield {
+    pub current: f32,
+    pub maximum: f32,
+    /// Shield points recharged per second.
+    pub regeneration: f32,
+    /// Seconds after a hit before the shield starts recharging.
+    pub regeneration_delay: f32,
+    /// Seconds since the shield last took a hit.
+    pub since_hit: f32,
+}
+
+impl Shield {
+    pub fn with_maximum(maximum: f32, regeneration: f32) -> Self {
+        Self {
+            current: maximum,
+            maximum,
+            regeneration,
+            regeneration_delay: 3.0,
+            since_hit: 0.0,
+        }
+    }
+
+    pub fn current_percent(&self) -> f32 {
+        if self.maximum <= 0.0 {
+            return 0.0;
+        }
+
+        self.current / self.maximum
+    }
+
+    /// Soaks up as much of the damage as the shield has left, returning what gets through.
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        if damage <= 0.0 {
+            return 0.0;
+        }
+
+        self.since_hit = 0.0;
+        let absorbed = damage.min(self.current);
+        self.current -= absorbed;
+        damage - absorbed
+    }
+
+    pub fn regenerate(&mut self, delta_secs: f32) {
+        self.since_hit += delta_secs;
+
+        if self.since_hit >= self.regeneration_delay {
+            self.current = (self.current + self.regeneration * delta_secs).min(self.maximum);
+        }
+    }
+}
+
+/// The velocity a body had before this frame's physics step, so impacts can be judged
+/// by how fast the bodies were going before they bounced off each other.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct ImpactVelocity(pub Vec2);
+
+/// Kinetic energy lost when two bodies collide head on and stick together,
+/// ie. the most an impact between them can turn into damage.
+pub fn impact_energy(mass1: f32, velocity1: Vec2, mass2: f32, velocity2: Vec2) -> f32 {
+    let total_mass = mass1 + mass2;
+    if total_mass <= 0.0 {
+        return 0.0;
+    }
+
+    let reduced_mass = mass1 * mass2 / total_mass;
+    0.5 * reduced_mass * (velocity1 - velocity2).length_squared()
+}
+
+#[test]
+fn test_impact_energy() {
+    // Equal masses meeting head on lose all of their kinetic energy.
+    let energy = impact_energy(2.0, Vec2::new(3.0, 0.0), 2.0, Vec2::new(-3.0, 0.0));
+    assert!((energy - 18.0).abs() < 1e-4);
+
+    // Only the relative velocity counts.
+    let moving_together = impact_energy(5.0, Vec2::splat(10.0), 1.0, Vec2::splat(10.0));
+    assert_eq!(moving_together, 0.0);
+
+    // A light body hitting a heavy one loses about all of its own energy.
+    let light = impact_energy(1.0, Vec2::new(10.0, 0.0), 1.0e6, Vec2::ZERO);
+    assert!((light - 50.0).abs() < 0.01);
+}
+
+#[test]
+fn test_shield_absorbs_resisted_damage_first() {
+    let resistances = Resistances::asteroid();
+    assert_eq!(resistances.damage_taken(100.0, DamageType::Kinetic), 25.0);
+    assert_eq!(resistances.damage_taken(100.0, DamageType::Thermal), 200.0);
+    assert_eq!(
+        resistances.damage_taken(100.0, DamageType::Explosive),
+        100.0
+    );
+
+    let mut shield = Shield::with_maximum(50.0, 10.0);
+    assert_eq!(shield.absorb(30.0), 0.0);
+    assert_eq!(shield.absorb(30.0), 10.0);
+    assert_eq!(shield.current, 0.0);
+
+    // Nothing comes back until the shield went without hits for a while.
+    shield.regenerate(1.0);
+    assert_eq!(shield.current, 0.0);
+    shield.regenerate(2.0);
+    assert_eq!(shield.current, 20.0);
+    shield.regenerate(10.0);
+    assert_eq!(shield.current, 50.0);
+}
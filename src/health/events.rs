@@ -1,9 +1,56 @@
 use bevy::prelude::{Entity, Event};
 
+use super::components::DamageType;
+
+/// Deals damage to the entity, before its resistances and shield.
 #[derive(Event)]
 pub struct DamageEvent {
     pub entity: Entity,
     pub damage: f32,
+    pub damage_type: DamageType,
+    /// The entity the damage came from, if any.
+    pub source: Option<Entity>,
+}
+
+impl DamageEvent {
+    /// Damage that kills the entity whatever its health and shield.
+    pub fn lethal(entity: Entity, damage_type: DamageType, source: Option<Entity>) -> Self {
+        Self {
+            entity,
+            damage: f32::INFINITY,
+            damage_type,
+            source,
+        }
+    }
+}
+
+/// The entity took damage. Sent after resistances and the shield had their say.
+#[derive(Event)]
+pub struct DamagedEvent {
+    pub entity: Entity,
+    pub source: Option<Entity>,
+    pub damage_type: DamageType,
+    /// Damage soaked up by the shield.
+    pub absorbed: f32,
+    /// Damage that went through to Health.
+    pub damage: f32,
+}
+
+/// The entity's Health ran out. Sent once, by the damage that finished it off.
+#[derive(Event)]
+pub struct KilledEvent {
+    pub entity: Entity,
+    /// The entity credited with the kill, if any.
+    pub source: Option<Entity>,
+    pub damage_type: DamageType,
+}
+
+/// Two bodies with Health ran into each other. Sent for each of the two bodies.
+#[derive(Event)]
+pub struct ImpactEvent {
+    pub entity: Entity,
+    pub other: Entity,
+    pub energy: f32,
 }
 
 #[derive(Event)]
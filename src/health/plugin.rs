@@ -1,8 +1,11 @@
-use bevy::prelude::{App, Plugin, Update};
+use bevy::prelude::{App, IntoSystemConfigs, Plugin, Update};
 
 use super::{
-    events::{DamageEvent, RepairEvent},
-    systems::{handle_damage_events, handle_repair_events},
+    events::{DamageEvent, DamagedEvent, ImpactEvent, KilledEvent, RepairEvent},
+    systems::{
+        add_impact_velocity, handle_damage_events, handle_impacts, handle_repair_events,
+        regenerate_shields, track_impact_velocity,
+    },
 };
 
 pub struct HealthPlugin;
@@ -10,7 +13,19 @@ pub struct HealthPlugin;
 impl Plugin for HealthPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<DamageEvent>()
+            .add_event::<DamagedEvent>()
+            .add_event::<KilledEvent>()
             .add_event::<RepairEvent>()
-            .add_systems(Update, (handle_damage_events, handle_repair_events));
+            .add_event::<ImpactEvent>()
+            .add_systems(
+                Update,
+                (
+                    (handle_impacts, track_impact_velocity).chain(),
+                    handle_damage_events.after(handle_impacts),
+                    handle_repair_events,
+                    regenerate_shields.before(handle_damage_events),
+                ),
+            )
+            .add_observer(add_impact_velocity);
     }
 }
@@ -1,21 +1,72 @@
-use bevy::{ecs::system::Query, prelude::EventReader};
+use avian2d::prelude::{CollisionStarted, LinearVelocity, Mass};
+use bevy::{
+    ecs::system::Query,
+    prelude::{Commands, EventReader, EventWriter, OnAdd, Res, Time, Trigger},
+};
 
 use super::{
-    components::Health,
-    events::{DamageEvent, RepairEvent},
+    components::{impact_energy, DamageType, Health, ImpactVelocity, Resistances, Shield},
+    events::{DamageEvent, DamagedEvent, ImpactEvent, KilledEvent, RepairEvent},
 };
 
+/// Impacts below this energy are bumps and scrapes that don't do any damage.
+const MIN_IMPACT_ENERGY: f32 = 1.0e5;
+/// Damage dealt to both bodies per unit of impact energy above the minimum.
+const IMPACT_DAMAGE_PER_ENERGY: f32 = 1.0e-5;
+
+/// Applies damage after the entity's resistances, letting its shield soak up what it can first.
 pub fn handle_damage_events(
     mut damage_events: EventReader<DamageEvent>,
-    mut entity_q: Query<&mut Health>,
+    mut entity_q: Query<(&mut Health, Option<&Resistances>, Option<&mut Shield>)>,
+    mut damaged_events: EventWriter<DamagedEvent>,
+    mut killed_events: EventWriter<KilledEvent>,
 ) {
     for evt in damage_events.read() {
-        if let Ok(mut health) = entity_q.get_mut(evt.entity) {
-            health.take_damage(evt.damage);
+        let Ok((mut health, resistances, shield)) = entity_q.get_mut(evt.entity) else {
+            continue;
+        };
+
+        // Already dead, the killing blow was dealt earlier.
+        if health.current() <= 0.0 {
+            continue;
+        }
+
+        let damage = resistances.map_or(evt.damage, |resistances| {
+            resistances.damage_taken(evt.damage, evt.damage_type)
+        });
+        let (through, absorbed) = shield.map_or((damage, 0.0), |mut shield| {
+            let before = shield.current;
+            (shield.absorb(damage), before - shield.current)
+        });
+
+        // Report what was actually lost, lethal damage is infinite.
+        let before = health.current();
+        health.take_damage(through);
+
+        damaged_events.send(DamagedEvent {
+            entity: evt.entity,
+            source: evt.source,
+            damage_type: evt.damage_type,
+            absorbed,
+            damage: before - health.current(),
+        });
+
+        if health.current() <= 0.0 {
+            killed_events.send(KilledEvent {
+                entity: evt.entity,
+                source: evt.source,
+                damage_type: evt.damage_type,
+            });
         }
     }
 }
 
+pub fn regenerate_shields(mut shield_q: Query<&mut Shield>, time: Res<Time>) {
+    for mut shield in shield_q.iter_mut() {
+        shield.regenerate(time.delta_secs());
+    }
+}
+
 pub fn handle_repair_events(
     mut damage_events: EventReader<RepairEvent>,
     mut entity_q: Query<&mut Health>,
@@ -26,3 +77,55 @@ pub fn handle_repair_events(
         }
     }
 }
+
+/// Every body that can take damage also takes damage from impacts.
+pub fn add_impact_velocity(trigger: Trigger<OnAdd, Health>, mut commands: Commands) {
+    commands
+        .entity(trigger.entity())
+        .insert(ImpactVelocity::default());
+}
+
+/// Remembers the velocity of bodies for the next frame's impacts.
+pub fn track_impact_velocity(mut body_q: Query<(&LinearVelocity, &mut ImpactVelocity)>) {
+    for (linear_velocity, mut impact_velocity) in body_q.iter_mut() {
+        impact_velocity.0 = linear_velocity.0.as_vec2();
+    }
+}
+
+/// Damages both bodies of a collision that just started by the energy of the impact,
+/// taken from their masses and their relative velocity before they bounced.
+pub fn handle_impacts(
+    mut collision_started: EventReader<CollisionStarted>,
+    body_q: Query<(&Mass, &ImpactVelocity)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut impact_events: EventWriter<ImpactEvent>,
+) {
+    for CollisionStarted(entity1, entity2) in collision_started.read() {
+        let (Ok((mass1, velocity1)), Ok((mass2, velocity2))) =
+            (body_q.get(*entity1), body_q.get(*entity2))
+        else {
+            continue;
+        };
+
+        let energy = impact_energy(mass1.0, velocity1.0, mass2.0, velocity2.0);
+        if energy < MIN_IMPACT_ENERGY {
+            continue;
+        }
+
+        let damage = (energy - MIN_IMPACT_ENERGY) * IMPACT_DAMAGE_PER_ENERGY;
+
+        for (entity, other) in [(*entity1, *entity2), (*entity2, *entity1)] {
+            damage_events.send(DamageEvent {
+                entity,
+                damage,
+                damage_type: DamageType::Collision,
+                source: Some(other),
+            });
+            impact_events.send(ImpactEvent {
+                entity,
+                other,
+                energy,
+            });
+        }
+    }
+}
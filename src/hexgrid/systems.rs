@@ -9,6 +9,7 @@ use hexx::{shapes, Hex, HexLayout, PlaneMeshBuilder};
 use crate::hexgrid::components::Building;
 use crate::player::components::Player;
 use crate::player_input::resources::MouseWorldPosition;
+use crate::projectile::components::Faction;
 
 use super::components::{BuildingType, HexTile};
 use super::events::BuildHexBuildingEvent;
@@ -77,6 +78,7 @@ pub fn setup_hex_grid(
                 .insert(collider.clone())
                 .insert(Name::new("HexTile"))
                 .insert(HexTile)
+                .insert(Faction::Player)
                 // .insert(Building(BuildingType::None))
                 .id();
             (hex, id)
@@ -1,6 +1,6 @@
-use crate::asteroid::components::AsteroidMaterial;
-use crate::items::{Amount, MetalIngot};
-use crate::upgrades::components::UpgradeComponent;
+use crate::items::components::ItemId;
+use crate::items::resources::ItemRegistry;
+use crate::items::Amount;
 use bevy::prelude::*;
 use ordered_float::OrderedFloat;
 use std::fmt;
@@ -10,200 +10,299 @@ pub struct Capacity {
     pub maximum: OrderedFloat<f32>,
 }
 
-#[derive(Component, Default, Debug, Clone)]
+#[derive(Component, Default, Debug, Clone, PartialEq)]
 pub struct Inventory {
     pub items: Vec<InventoryItem>,
     pub capacity: Capacity,
+    /// Inventories in slot mode also lay their items out in a fixed number of slots.
+    pub slots: Option<SlotGrid>,
 }
 
-#[derive(Clone, PartialEq, Hash)]
-
-pub enum InventoryItem {
-    Material(AsteroidMaterial, Amount),
-    Ingot(MetalIngot, Amount),
-    Component(UpgradeComponent, Amount),
-}
-
-impl Default for InventoryItem {
-    fn default() -> Self {
-        InventoryItem::Material(AsteroidMaterial::Iron, Amount::Weight(OrderedFloat(0.0)))
-    }
-}
+/// An amount of a single kind of item, identified by its id in the ItemRegistry.
+#[derive(Default, Clone, PartialEq, Hash)]
+pub struct InventoryItem(pub ItemId, pub Amount);
 
 impl fmt::Debug for InventoryItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Material(arg0, arg1) => {
-                write!(f, "{:?}: {:?}", arg0, arg1)
-            }
-            Self::Ingot(arg0, arg1) => {
-                write!(f, "{:?}: {:?}", arg0, arg1)
-            }
-            Self::Component(arg0, arg1) => {
-                write!(f, "{:?}: {:?}", arg0, arg1)
-            }
-        }
+        write!(f, "{:?}: {:?}", self.0, self.1)
     }
 }
 
 impl InventoryItem {
+    pub fn new(id: impl Into<ItemId>, amount: Amount) -> Self {
+        Self(id.into(), amount)
+    }
+
+    pub fn id(&self) -> &ItemId {
+        &self.0
+    }
+
     pub fn amount(&self) -> Amount {
-        match self {
-            InventoryItem::Material(_, weight) => weight.clone(),
-            InventoryItem::Ingot(_, quantity) => quantity.clone(),
-            InventoryItem::Component(_, quantity) => quantity.clone(),
-        }
+        self.1.clone()
     }
 
-    pub fn add_amount(&mut self, to_add: Amount) {
-        match self {
-            InventoryItem::Material(_, ref mut weight) => {
-                *weight += to_add;
-            }
-            InventoryItem::Ingot(_, ref mut quantity) => {
-                *quantity += to_add;
-            }
-            InventoryItem::Component(_, ref mut quantity) => {
-                *quantity += to_add;
+    /// Adds to the amount. Returns false, leaving it unchanged, if the amounts
+    /// are of different kinds, ie. after the item's stacking changed.
+    pub fn add_amount(&mut self, to_add: Amount) -> bool {
+        match self.1.checked_add(&to_add) {
+            Some(sum) => {
+                self.1 = sum;
+                true
             }
+            None => false,
         }
     }
 
-    pub fn remove_amount(&mut self, to_remove: Amount) {
-        match self {
-            InventoryItem::Material(_, ref mut weight) => {
-                *weight -= to_remove;
-            }
-            InventoryItem::Ingot(_, ref mut quantity) => {
-                *quantity -= to_remove;
-            }
-            InventoryItem::Component(_, ref mut quantity) => {
-                *quantity -= to_remove;
+    /// Takes away from the amount. Returns false, leaving it unchanged, if there isn't
+    /// enough or the amounts are of different kinds.
+    pub fn remove_amount(&mut self, to_remove: Amount) -> bool {
+        match self.1.checked_sub(&to_remove) {
+            Some(rest) => {
+                self.1 = rest;
+                true
             }
+            None => false,
         }
     }
 }
 
+/// The outcome of an `Inventory::transfer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferResult {
+    pub requested: Amount,
+    pub transferred: Amount,
+}
+
+impl TransferResult {
+    /// True if everything that was requested has been moved.
+    pub fn is_complete(&self) -> bool {
+        self.transferred == self.requested
+    }
+}
+
 impl Inventory {
-    // TODO: find a way to clean this up.
+    /// An inventory without a weight limit, used for buffers that aren't cargo holds.
+    pub fn unbounded() -> Self {
+        Self {
+            capacity: Capacity {
+                maximum: OrderedFloat(f32::INFINITY),
+            },
+            ..default()
+        }
+    }
+
+    /// Returns the inventory entry for the item with the given id, if there is one.
+    pub fn get(&self, id: &ItemId) -> Option<&InventoryItem> {
+        self.items.iter().find(|item| item.id() == id)
+    }
+
     pub fn has_items(&self, items: Vec<InventoryItem>) -> bool {
-        for item in items.iter() {
-            // FIXME: this fells messy and error prone.. not even sure its right haha... maybe use the macro from discord
-
-            match item {
-                InventoryItem::Component(needed_comp, amount) => {
-                    if let Some(inventory_comp) = self.items.iter().find(
-                        |item| matches!(item, InventoryItem::Component(c, _) if c == needed_comp),
-                    ) {
-                        if inventory_comp.amount() < *amount {
-                            return false;
-                        }
-                    } else {
-                        return false;
-                    }
-                }
-                InventoryItem::Ingot(needed_ingot, amount) => {
-                    if let Some(inventory_ingot) = self.items.iter().find(
-                        |item| matches!(item, InventoryItem::Ingot(i, _) if i == needed_ingot),
-                    ) {
-                        if inventory_ingot.amount() < *amount {
-                            return false;
-                        }
-                    } else {
-                        return false;
-                    }
-                }
-                InventoryItem::Material(needed_mat, amount) => {
-                    if let Some(inventory_mat) = self.items.iter().find(
-                        |item| matches!(item, InventoryItem::Material(m, _) if m == needed_mat),
-                    ) {
-                        if inventory_mat.amount() < *amount {
-                            return false;
-                        }
-                    } else {
-                        return false;
-                    }
-                }
-            }
+        items.iter().all(|needed| {
+            self.get(needed.id())
+                .is_some_and(|found| found.amount() >= needed.amount())
+        })
+    }
+
+    pub fn has_capacity_for(&self, item: &InventoryItem, registry: &ItemRegistry) -> bool {
+        self.fitting_amount(item, registry) == item.amount()
+    }
+
+    pub fn remaining_capacity(&self, registry: &ItemRegistry) -> OrderedFloat<f32> {
+        self.capacity.maximum - self.gross_material_weight(registry)
+    }
+
+    /// Returns the current gross weight of all items in the inventory
+    pub fn gross_material_weight(&self, registry: &ItemRegistry) -> OrderedFloat<f32> {
+        OrderedFloat(self.items.iter().map(|item| registry.weight_of(item)).sum())
+    }
+
+    /// Returns the largest part of the item's amount that fits in the remaining capacity.
+    /// Quantities are rounded down to whole units.
+    /// In slot mode the free room in the slots is a limit as well.
+    pub fn fitting_amount(&self, item: &InventoryItem, registry: &ItemRegistry) -> Amount {
+        let unit_weight = registry.unit_weight(item.id());
+
+        let mut fitting_units = if unit_weight <= 0.0 {
+            f32::INFINITY
+        } else {
+            self.remaining_capacity(registry).0.max(0.0) / unit_weight
+        };
+
+        if let Some(slots) = &self.slots {
+            fitting_units = fitting_units.min(slots.room_for(item.id(), registry));
         }
 
-        true
+        item.amount()
+            .with_units(item.amount().units().min(fitting_units))
     }
 
-    pub fn has_capacity_for(&self, item: &InventoryItem) -> bool {
-        match item.amount() {
-            Amount::Weight(w) => self.remaining_capacity() >= w,
-            Amount::Quantity(_) => {
-                // TODO: calculate weight with quantity * item_weight
-                true
-            }
-            Amount::None => true,
+    pub fn add_to_inventory(
+        &mut self,
+        item_to_add: &InventoryItem,
+        registry: &ItemRegistry,
+    ) -> bool {
+        if !self.has_capacity_for(item_to_add, registry) {
+            debug!("Not enough capacity for {:?}", item_to_add);
+            return false;
         }
+
+        self.merge_item(item_to_add, registry).is_zero()
     }
 
-    pub fn remaining_capacity(&self) -> OrderedFloat<f32> {
-        self.capacity.maximum - self.gross_material_weight()
+    /// Merges the item into an existing entry of the same kind, or adds a new entry.
+    /// In slot mode only the part that fits in the slots is added.
+    /// Returns the part of the item that wasn't added, which is all of it if the
+    /// existing entry holds a different kind of amount.
+    fn merge_item(&mut self, item_to_add: &InventoryItem, registry: &ItemRegistry) -> Amount {
+        if self
+            .get(item_to_add.id())
+            .is_some_and(|found| found.amount().checked_add(&item_to_add.amount()).is_none())
+        {
+            return item_to_add.amount();
+        }
+
+        let leftover = match self.slots.as_mut() {
+            Some(slots) => slots.store(item_to_add, registry),
+            None => item_to_add.amount().with_units(0.0),
+        };
+
+        let added = item_to_add
+            .amount()
+            .with_units(item_to_add.amount().units() - leftover.units());
+        if added.is_zero() {
+            return leftover;
+        }
+
+        if let Some(found) = self
+            .items
+            .iter_mut()
+            .find(|item| item.id() == item_to_add.id())
+        {
+            found.add_amount(added);
+        } else {
+            self.items
+                .push(InventoryItem(item_to_add.id().clone(), added));
+        }
+
+        leftover
     }
 
-    /// Returns the current gross weight of materials in the inventory
-    pub fn gross_material_weight(&self) -> OrderedFloat<f32> {
-        let mut gross_weight = 0.0;
+    /// Moves up to `amount` of an item from this inventory into `to`.
+    /// Only the part that this inventory holds and `to` has room for is moved,
+    /// and it is removed from here exactly as it is added there.
+    pub fn transfer(
+        &mut self,
+        to: &mut Inventory,
+        item: &ItemId,
+        amount: Amount,
+        registry: &ItemRegistry,
+    ) -> TransferResult {
+        let available = self
+            .get(item)
+            .map(|found| found.amount())
+            .unwrap_or_default();
 
-        for item in self.items.iter() {
-            match item.amount() {
-                Amount::Weight(w) => {
-                    gross_weight += w.0;
-                }
-                Amount::Quantity(_q) => {
-                    // TODO: calculate weight with quantity * item_weight
-                }
-                Amount::None => {}
+        let transferable = match (&available, &amount) {
+            (Amount::Weight(held), Amount::Weight(wanted)) => Amount::Weight(*held.min(wanted)),
+            (Amount::Quantity(held), Amount::Quantity(wanted)) => {
+                Amount::Quantity(*held.min(wanted))
             }
+            _ => Amount::None,
+        };
+
+        let mut transferred =
+            to.fitting_amount(&InventoryItem(item.clone(), transferable), registry);
+
+        if !transferred.is_zero() {
+            let moved = InventoryItem(item.clone(), transferred.clone());
+
+            if !self.remove_from_inventory(&moved) {
+                return TransferResult {
+                    requested: amount,
+                    transferred: Amount::None,
+                };
+            }
+
+            // Whatever `to` couldn't take after all goes back where it came from.
+            let leftover = to.merge_item(&moved, registry);
+            if !leftover.is_zero() {
+                self.merge_item(&InventoryItem(item.clone(), leftover.clone()), registry);
+                transferred = transferred.with_units(transferred.units() - leftover.units());
+            }
+        }
+
+        TransferResult {
+            requested: amount,
+            transferred,
         }
+    }
 
-        OrderedFloat(gross_weight)
+    /// Transfers every item in this inventory into `to`, as far as capacity allows.
+    pub fn transfer_all(
+        &mut self,
+        to: &mut Inventory,
+        registry: &ItemRegistry,
+    ) -> Vec<TransferResult> {
+        self.items
+            .clone()
+            .iter()
+            .map(|item| self.transfer(to, item.id(), item.amount(), registry))
+            .collect()
     }
 
-    pub fn add_to_inventory(&mut self, item_to_add: &InventoryItem) -> bool {
-        if self.has_capacity_for(item_to_add) {
-            match item_to_add {
-                InventoryItem::Material(material, _weight) => {
-                    if let Some(found) = self.items.iter_mut().find_map(|item| match item {
-                        InventoryItem::Material(m, _) if *m == *material => Some(item),
-                        _ => None,
-                    }) {
-                        found.add_amount(item_to_add.amount());
-                    } else {
-                        self.items.push(item_to_add.clone());
-                    }
-                }
-                InventoryItem::Ingot(ingot, _quantity) => {
-                    if let Some(found) = self.items.iter_mut().find_map(|item| match item {
-                        InventoryItem::Ingot(i, _) if *i == *ingot => Some(item),
-                        _ => None,
-                    }) {
-                        found.add_amount(item_to_add.amount())
-                    } else {
-                        self.items.push(item_to_add.clone());
-                    }
-                }
-                InventoryItem::Component(component, _quantity) => {
-                    if let Some(found) = self.items.iter_mut().find_map(|item| match item {
-                        InventoryItem::Component(i, _) if *i == *component => Some(item),
-                        _ => None,
-                    }) {
-                        found.add_amount(item_to_add.amount())
-                    } else {
-                        self.items.push(item_to_add.clone());
-                    }
+    /// Moves all of the items into `to`, or nothing at all if any of them are missing
+    /// or don't fit. On failure returns the items that couldn't be moved.
+    pub fn transfer_items(
+        &mut self,
+        to: &mut Inventory,
+        items: &[InventoryItem],
+        registry: &ItemRegistry,
+    ) -> Result<(), Vec<InventoryItem>> {
+        // Requirements listing the same item twice must be covered by their combined amount.
+        let mut combined: Vec<InventoryItem> = vec![];
+        for item in items {
+            if let Some(found) = combined.iter_mut().find(|found| found.id() == item.id()) {
+                if !found.add_amount(item.amount()) {
+                    return Err(vec![item.clone()]);
                 }
+            } else {
+                combined.push(item.clone());
             }
-        } else {
-            println!("NOT ENOUGH CAPACITY FOR: {:?}", item_to_add);
-            return false;
         }
 
-        true
+        // Check what's obviously missing or too big first, so a transfer that can't happen
+        // doesn't pay for copying both inventories.
+        let unavailable: Vec<InventoryItem> = combined
+            .iter()
+            .filter(|item| {
+                !self.has_items(vec![(*item).clone()]) || !to.has_capacity_for(item, registry)
+            })
+            .cloned()
+            .collect();
+
+        if !unavailable.is_empty() {
+            return Err(unavailable);
+        }
+
+        let mut source = self.clone();
+        let mut destination = to.clone();
+
+        let failed: Vec<InventoryItem> = combined
+            .into_iter()
+            .filter(|item| {
+                !source
+                    .transfer(&mut destination, item.id(), item.amount(), registry)
+                    .is_complete()
+            })
+            .collect();
+
+        if !failed.is_empty() {
+            return Err(failed);
+        }
+
+        *self = source;
+        *to = destination;
+        Ok(())
     }
 
     pub fn remove_all_from_inventory(&mut self, items: Vec<InventoryItem>) -> bool {
@@ -213,79 +312,399 @@ impl Inventory {
             all_removed &= self.remove_from_inventory(&item);
         }
 
-        println!("ALLREMOVED: {}", all_removed);
         all_removed
     }
 
     pub fn remove_from_inventory(&mut self, item_to_remove: &InventoryItem) -> bool {
-        match item_to_remove {
-            InventoryItem::Material(to_find, _) => {
-                if let Some((index, found_item)) =
-                    self.items
-                        .iter_mut()
-                        .enumerate()
-                        .find_map(|(index, item)| match item {
-                            InventoryItem::Material(m, _) if *m == *to_find => Some((index, item)),
-                            _ => None,
-                        })
-                {
-                    if found_item.amount() >= item_to_remove.amount() {
-                        found_item.remove_amount(item_to_remove.amount());
-                        if found_item.amount() == Amount::Weight(OrderedFloat(0.0)) {
-                            self.items.remove(index);
-                        }
-                        return true;
-                    } else {
-                        return false;
-                    }
-                }
+        let Some(index) = self
+            .items
+            .iter()
+            .position(|item| item.id() == item_to_remove.id())
+        else {
+            return false;
+        };
+
+        let found_item = &mut self.items[index];
+
+        if !found_item.remove_amount(item_to_remove.amount()) {
+            return false;
+        }
+
+        if found_item.amount().is_zero() {
+            self.items.remove(index);
+        }
+
+        if let Some(slots) = self.slots.as_mut() {
+            slots.take(item_to_remove);
+        }
+
+        true
+    }
+}
+
+/// Orders in which the stacks of a SlotGrid can be sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotSortOrder {
+    Category,
+    Value,
+    Weight,
+}
+
+/// A single cargo slot holding at most one stack of a single item.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Slot {
+    pub stack: Option<InventoryItem>,
+    /// Locked slots stay put when sorting and are never filled automatically.
+    pub locked: bool,
+}
+
+/// A fixed number of cargo slots. Slots only lay out what is already counted
+/// in the inventory's items, so rearranging them never changes the totals.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SlotGrid {
+    pub slots: Vec<Slot>,
+}
+
+impl SlotGrid {
+    pub fn new(size: usize) -> Self {
+        Self {
+            slots: vec![Slot::default(); size],
+        }
+    }
+
+    pub fn add_slots(&mut self, count: usize) {
+        self.slots.extend(vec![Slot::default(); count]);
+    }
+
+    pub fn toggle_lock(&mut self, index: usize) {
+        if let Some(slot) = self.slots.get_mut(index) {
+            slot.locked = !slot.locked;
+        }
+    }
+
+    /// Returns how many units of the item still fit in the unlocked slots.
+    pub fn room_for(&self, id: &ItemId, registry: &ItemRegistry) -> f32 {
+        let max_stack = registry.max_stack(id).unwrap_or(f32::INFINITY);
+
+        self.slots
+            .iter()
+            .filter(|slot| !slot.locked)
+            .map(|slot| match &slot.stack {
+                None => max_stack,
+                Some(stack) if stack.id() == id => (max_stack - stack.amount().units()).max(0.0),
+                Some(_) => 0.0,
+            })
+            .sum()
+    }
+
+    /// Places the item in the unlocked slots, topping up existing stacks before
+    /// starting new ones. Returns the part of the item that didn't fit.
+    fn store(&mut self, item: &InventoryItem, registry: &ItemRegistry) -> Amount {
+        let max_stack = registry.max_stack(item.id()).unwrap_or(f32::INFINITY);
+        let mut remaining = item.amount().units();
+
+        for slot in self.slots.iter_mut().filter(|slot| !slot.locked) {
+            if let Some(stack) = slot.stack.as_mut().filter(|stack| stack.id() == item.id()) {
+                let added = (max_stack - stack.amount().units()).clamp(0.0, remaining);
+                stack.add_amount(item.amount().with_units(added));
+                remaining -= added;
             }
-            InventoryItem::Ingot(to_find, _) => {
-                if let Some((index, found_item)) =
-                    self.items
-                        .iter_mut()
-                        .enumerate()
-                        .find_map(|(index, item)| match item {
-                            InventoryItem::Ingot(i, _) if *i == *to_find => Some((index, item)),
-                            _ => None,
-                        })
-                {
-                    if found_item.amount() >= item_to_remove.amount() {
-                        found_item.remove_amount(item_to_remove.amount());
-
-                        if found_item.amount() == Amount::Quantity(0) {
-                            self.items.remove(index);
-                        }
-                        return true;
-                    } else {
-                        return false;
-                    }
-                }
+        }
+
+        for slot in self
+            .slots
+            .iter_mut()
+            .filter(|slot| !slot.locked && slot.stack.is_none())
+        {
+            if remaining <= 0.0 {
+                break;
+            }
+
+            let added = max_stack.min(remaining);
+            slot.stack = Some(InventoryItem(
+                item.id().clone(),
+                item.amount().with_units(added),
+            ));
+            remaining -= added;
+        }
+
+        item.amount().with_units(remaining)
+    }
+
+    /// Takes the item out of its stacks, emptying unlocked slots before locked ones.
+    fn take(&mut self, item: &InventoryItem) {
+        let mut remaining = item.amount().units();
+
+        let mut order: Vec<usize> = (0..self.slots.len()).rev().collect();
+        order.sort_by_key(|index| self.slots[*index].locked);
+
+        for index in order {
+            if remaining <= 0.0 {
+                break;
+            }
+
+            let slot = &mut self.slots[index];
+            let Some(stack) = slot.stack.as_mut().filter(|stack| stack.id() == item.id()) else {
+                continue;
+            };
+
+            let taken = stack.amount().units().min(remaining);
+            stack.remove_amount(item.amount().with_units(taken));
+            remaining -= taken;
+
+            if stack.amount().is_zero() {
+                slot.stack = None;
+            }
+        }
+    }
+
+    /// Moves part or all of the stack in `from` into the empty slot `to`.
+    pub fn split(&mut self, from: usize, to: usize, amount: Amount) -> bool {
+        if from == to || self.slots.get(to).is_none_or(|slot| slot.stack.is_some()) {
+            return false;
+        }
+
+        let Some(stack) = self
+            .slots
+            .get_mut(from)
+            .and_then(|slot| slot.stack.as_mut())
+        else {
+            return false;
+        };
+
+        let units = amount.units();
+        if units <= 0.0 || units > stack.amount().units() {
+            return false;
+        }
+
+        let moved = stack.amount().with_units(units);
+        stack.remove_amount(moved.clone());
+        let split = InventoryItem(stack.id().clone(), moved);
+
+        if stack.amount().is_zero() {
+            self.slots[from].stack = None;
+        }
+        self.slots[to].stack = Some(split);
+
+        true
+    }
+
+    /// Moves as much of the stack in `from` onto the stack of the same item in `to`
+    /// as its stack limit allows.
+    pub fn merge(&mut self, from: usize, to: usize, registry: &ItemRegistry) -> bool {
+        if from == to || from >= self.slots.len() || to >= self.slots.len() {
+            return false;
+        }
+
+        let (Some(source), Some(target)) =
+            (self.slots[from].stack.clone(), self.slots[to].stack.clone())
+        else {
+            return false;
+        };
+
+        if source.id() != target.id() {
+            return false;
+        }
+
+        let max_stack = registry.max_stack(source.id()).unwrap_or(f32::INFINITY);
+        let units = (max_stack - target.amount().units()).clamp(0.0, source.amount().units());
+        if units <= 0.0 {
+            return false;
+        }
+
+        let moved = source.amount().with_units(units);
+
+        if let Some(target) = self.slots[to].stack.as_mut() {
+            target.add_amount(moved.clone());
+        }
+        if let Some(source) = self.slots[from].stack.as_mut() {
+            source.remove_amount(moved);
+            if source.amount().is_zero() {
+                self.slots[from].stack = None;
             }
-            InventoryItem::Component(to_find, _) => {
-                if let Some((index, found_item)) =
-                    self.items
-                        .iter_mut()
-                        .enumerate()
-                        .find_map(|(index, item)| match item {
-                            InventoryItem::Component(i, _) if *i == *to_find => Some((index, item)),
-                            _ => None,
-                        })
-                {
-                    if found_item.amount() >= item_to_remove.amount() {
-                        found_item.remove_amount(item_to_remove.amount());
-
-                        if found_item.amount() == Amount::Quantity(0) {
-                            self.items.remove(index);
-                        }
-                        return true;
-                    } else {
-                        return false;
-                    }
+        }
+
+        true
+    }
+
+    /// Combines and sorts the stacks in the unlocked slots. Locked slots keep their contents.
+    /// If the sorted stacks don't fit, the slots are left as they were and the items
+    /// that didn't fit are returned.
+    pub fn sort(
+        &mut self,
+        order: SlotSortOrder,
+        registry: &ItemRegistry,
+    ) -> Result<(), Vec<InventoryItem>> {
+        let mut sorted = self.clone();
+        let mut totals: Vec<InventoryItem> = vec![];
+
+        for slot in sorted.slots.iter_mut().filter(|slot| !slot.locked) {
+            if let Some(stack) = slot.stack.take() {
+                if let Some(total) = totals.iter_mut().find(|total| total.id() == stack.id()) {
+                    total.add_amount(stack.amount());
+                } else {
+                    totals.push(stack);
                 }
             }
         }
 
-        false
+        totals.sort_by(|a, b| match order {
+            SlotSortOrder::Category => registry
+                .category(a.id())
+                .cmp(&registry.category(b.id()))
+                .then_with(|| registry.name(a.id()).cmp(&registry.name(b.id()))),
+            SlotSortOrder::Value => registry.value_of(b).total_cmp(&registry.value_of(a)),
+            SlotSortOrder::Weight => registry.weight_of(b).total_cmp(&registry.weight_of(a)),
+        });
+
+        let overflow: Vec<InventoryItem> = totals
+            .iter()
+            .map(|item| InventoryItem(item.id().clone(), sorted.store(item, registry)))
+            .filter(|leftover| !leftover.amount().is_zero())
+            .collect();
+
+        if !overflow.is_empty() {
+            return Err(overflow);
+        }
+
+        *self = sorted;
+        Ok(())
     }
 }
+
+#[test]
+fn test_transfer_partial_fill() {
+    use crate::items::components::Stacking;
+
+    let registry = ItemRegistry::for_tests(&[("iron_ingot", Stacking::Quantity, 10.0, None)]);
+
+    let mut ship = Inventory {
+        items: vec![InventoryItem::new("iron_ingot", Amount::Quantity(5))],
+        capacity: Capacity {
+            maximum: OrderedFloat(100.0),
+        },
+        slots: None,
+    };
+    let mut station = Inventory {
+        items: vec![],
+        capacity: Capacity {
+            maximum: OrderedFloat(35.0),
+        },
+        slots: None,
+    };
+
+    let result = ship.transfer(
+        &mut station,
+        &ItemId::from("iron_ingot"),
+        Amount::Quantity(5),
+        &registry,
+    );
+
+    assert_eq!(result.transferred, Amount::Quantity(3));
+    assert!(!result.is_complete());
+    assert_eq!(
+        ship.get(&ItemId::from("iron_ingot"))
+            .map(|item| item.amount()),
+        Some(Amount::Quantity(2))
+    );
+    assert_eq!(
+        station
+            .get(&ItemId::from("iron_ingot"))
+            .map(|item| item.amount()),
+        Some(Amount::Quantity(3))
+    );
+}
+
+#[test]
+fn test_slots_respect_stack_limits() {
+    use crate::items::components::Stacking;
+
+    let registry = ItemRegistry::for_tests(&[("cog", Stacking::Quantity, 1.0, Some(10.0))]);
+
+    let mut inventory = Inventory {
+        items: vec![],
+        capacity: Capacity {
+            maximum: OrderedFloat(1000.0),
+        },
+        slots: Some(SlotGrid::new(2)),
+    };
+
+    let cogs = InventoryItem::new("cog", Amount::Quantity(25));
+    assert_eq!(
+        inventory.fitting_amount(&cogs, &registry),
+        Amount::Quantity(20)
+    );
+    assert!(!inventory.add_to_inventory(&cogs, &registry));
+    assert!(inventory.add_to_inventory(&InventoryItem::new("cog", Amount::Quantity(15)), &registry));
+
+    let slots = inventory.slots.as_ref().unwrap();
+    assert_eq!(
+        slots.slots[0].stack,
+        Some(InventoryItem::new("cog", Amount::Quantity(10)))
+    );
+    assert_eq!(
+        slots.slots[1].stack,
+        Some(InventoryItem::new("cog", Amount::Quantity(5)))
+    );
+
+    inventory.remove_from_inventory(&InventoryItem::new("cog", Amount::Quantity(5)));
+    assert_eq!(inventory.slots.as_ref().unwrap().slots[1].stack, None);
+}
+
+#[test]
+fn test_sort_keeps_the_slots_when_the_stacks_dont_fit() {
+    use crate::items::components::Stacking;
+
+    let registry = ItemRegistry::for_tests(&[("cog", Stacking::Quantity, 1.0, Some(5.0))]);
+
+    // Stacks laid out under a larger stack limit than the one now in the registry.
+    let mut slots = SlotGrid::new(2);
+    slots.slots[0].stack = Some(InventoryItem::new("cog", Amount::Quantity(8)));
+    slots.slots[1].stack = Some(InventoryItem::new("cog", Amount::Quantity(4)));
+    let unsorted = slots.clone();
+
+    assert_eq!(
+        slots.sort(SlotSortOrder::Category, &registry),
+        Err(vec![InventoryItem::new("cog", Amount::Quantity(2))])
+    );
+    assert_eq!(slots, unsorted);
+}
+
+#[test]
+fn test_amounts_of_a_different_kind_are_never_enough() {
+    use crate::items::components::Stacking;
+
+    // The station still holds ore counted by quantity from before its stacking changed.
+    let registry = ItemRegistry::for_tests(&[("iron_ore", Stacking::Weight, 1.0, None)]);
+    let stale = InventoryItem::new("iron_ore", Amount::Quantity(5));
+    let ore = InventoryItem::new("iron_ore", Amount::Weight(OrderedFloat(20.0)));
+
+    let mut station = Inventory {
+        items: vec![stale.clone()],
+        capacity: Capacity {
+            maximum: OrderedFloat(1000.0),
+        },
+        slots: None,
+    };
+    let mut ship = Inventory {
+        items: vec![ore.clone()],
+        capacity: Capacity {
+            maximum: OrderedFloat(1000.0),
+        },
+        slots: None,
+    };
+
+    assert!(!station.has_items(vec![ore.clone()]));
+    assert!(!station.remove_from_inventory(&ore));
+    assert!(!station.add_to_inventory(&ore, &registry));
+
+    let result = ship.transfer(
+        &mut station,
+        &ItemId::from("iron_ore"),
+        ore.amount(),
+        &registry,
+    );
+    assert!(result.transferred.is_zero());
+    assert_eq!(ship.items, vec![ore]);
+    assert_eq!(station.items, vec![stale]);
+}
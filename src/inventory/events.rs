@@ -1,3 +1,10 @@
-// Add Item to Inventory Event
-// Remove Item from Inventory Event
-// Transfer Item Between Inventories Event
+use bevy::prelude::*;
+
+/// Sent once per frame for every entity whose inventory changed during that frame.
+/// Changes are coalesced: however many items were added, removed or moved, there is a single
+/// event, and it doesn't say what changed. Read the inventory itself to find out.
+/// It is sent in PostUpdate, so systems in Update read it the frame after the change.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct InventoryChangedEvent {
+    pub entity: Entity,
+}
@@ -1,12 +1,16 @@
-use bevy::app::{App, Plugin, Update};
+use bevy::app::{App, Plugin, PostUpdate};
 
-use super::systems::deposit_inventory;
+use super::{
+    events::InventoryChangedEvent,
+    systems::{deposit_inventory, send_inventory_changed_events},
+};
 
 pub struct InventoryPlugin;
 
 impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
-        app
-            .add_observer(deposit_inventory);
+        app.add_event::<InventoryChangedEvent>()
+            .add_observer(deposit_inventory)
+            .add_systems(PostUpdate, send_inventory_changed_events);
     }
 }
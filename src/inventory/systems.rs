@@ -1,32 +1,35 @@
-use super::components::{Inventory, InventoryItem};
+use super::{
+    components::{Inventory, InventoryItem},
+    events::InventoryChangedEvent,
+};
 use bevy::prelude::*;
 
 use crate::{
-    items::{Amount, MetalIngot},
+    items::{resources::ItemRegistry, Amount},
     player::{components::Player, resources::EmptyInventoryDepositTimer},
     player_input::events::DepositInventoryEvent,
     space_station::components::SpaceStation,
-    upgrades::components::UpgradeComponent,
 };
 
 pub fn attach_inventory_to_entity(
     commands: &mut Commands,
     mut inventory: Inventory,
     entity: Entity,
+    item_registry: &ItemRegistry,
 ) {
     // TODO: REMOVE ONLY FOR TESTING.
-    inventory.add_to_inventory(&InventoryItem::Ingot(
-        MetalIngot::IronIngot,
-        Amount::Quantity(5),
-    ));
-    inventory.add_to_inventory(&InventoryItem::Component(
-        UpgradeComponent::Cog,
-        Amount::Quantity(2),
-    ));
-    inventory.add_to_inventory(&InventoryItem::Component(
-        UpgradeComponent::IronPlate,
-        Amount::Quantity(2),
-    ));
+    inventory.add_to_inventory(
+        &InventoryItem::new("iron_ingot", Amount::Quantity(5)),
+        item_registry,
+    );
+    inventory.add_to_inventory(
+        &InventoryItem::new("cog", Amount::Quantity(2)),
+        item_registry,
+    );
+    inventory.add_to_inventory(
+        &InventoryItem::new("iron_plate", Amount::Quantity(2)),
+        item_registry,
+    );
     commands.entity(entity).insert(inventory);
 }
 
@@ -36,6 +39,7 @@ pub fn deposit_inventory(
     mut player_query: Query<&mut Inventory, (With<Player>, Without<SpaceStation>)>,
     mut base_station_query: Query<&mut Inventory, (With<SpaceStation>, Without<Player>)>,
     mut empty_deposit_timer: ResMut<EmptyInventoryDepositTimer>,
+    item_registry: Res<ItemRegistry>,
 ) {
     let mut player_inventory = player_query.single_mut();
     let mut base_station_inventory = base_station_query.single_mut();
@@ -45,8 +49,17 @@ pub fn deposit_inventory(
         *timer = EmptyInventoryDepositTimer(Some(Timer::from_seconds(3.0, TimerMode::Once)));
     }
 
-    for item in player_inventory.clone().items.iter() {
-        base_station_inventory.add_to_inventory(item);
-        player_inventory.remove_from_inventory(item);
+    // Deposit as much of each item as the base station has room for.
+    player_inventory.transfer_all(&mut base_station_inventory, &item_registry);
+}
+
+/// Sends an InventoryChangedEvent for every inventory that was mutated this frame,
+/// one per inventory no matter how many times it was mutated.
+pub fn send_inventory_changed_events(
+    inventory_query: Query<Entity, Changed<Inventory>>,
+    mut inventory_changed_events: EventWriter<InventoryChangedEvent>,
+) {
+    for entity in inventory_query.iter() {
+        inventory_changed_events.send(InventoryChangedEvent { entity });
     }
 }
@@ -0,0 +1,620 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::prelude::*;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+
+use serde::Deserialize;
+
+use crate::{
+    inventory::components::{Inventory, InventoryItem},
+    items::{resources::ItemRegistry, Amount},
+    recipes::components::Recipe,
+    space_station::modules::components::SpaceStationModuleType,
+};
+
+use super::ItemProducer;
+
+/// The kinds of station modules that produce items, each with its own recipes in the recipe book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum ProducerKind {
+    Refinery,
+    Factory,
+}
+
+impl ProducerKind {
+    /// Returns the kind of producer built for a station module, if it is one.
+    pub fn from_module_type(module_type: SpaceStationModuleType) -> Option<Self> {
+        match module_type {
+            SpaceStationModuleType::Refinery => Some(ProducerKind::Refinery),
+            SpaceStationModuleType::Factory => Some(ProducerKind::Factory),
+            _ => None,
+        }
+    }
+}
+
+/// A station module that turns items from the station's inventory into other items.
+/// Every producer runs its own production queue, so any number of them work in parallel.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Producer {
+    pub kind: ProducerKind,
+    pub recipes: Vec<Recipe>,
+    pub queue: ProductionQueue,
+}
+
+impl Producer {
+    pub fn new(kind: ProducerKind, recipes: Vec<Recipe>) -> Self {
+        Self {
+            kind,
+            recipes,
+            queue: ProductionQueue::default(),
+        }
+    }
+}
+
+impl ItemProducer for Producer {
+    fn recipes(&self) -> Vec<Recipe> {
+        self.recipes.clone()
+    }
+
+    fn queue(&self) -> &ProductionQueue {
+        &self.queue
+    }
+
+    fn queue_mut(&mut self) -> &mut ProductionQueue {
+        &mut self.queue
+    }
+}
+
+/// Number of jobs a producer can have waiting in its queue by default.
+pub const DEFAULT_QUEUE_LENGTH: usize = 8;
+
+/// How many more times a queued job should be produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobRepeat {
+    Times(u32),
+    Forever,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionJob {
+    pub recipe: Recipe,
+    pub repeat: JobRepeat,
+}
+
+impl ProductionJob {
+    /// Returns the job for the remaining runs after this one, if there are any.
+    fn next_run(&self) -> Option<ProductionJob> {
+        let repeat = match self.repeat {
+            JobRepeat::Times(times) if times > 1 => JobRepeat::Times(times - 1),
+            JobRepeat::Times(_) => return None,
+            JobRepeat::Forever => JobRepeat::Forever,
+        };
+
+        Some(ProductionJob {
+            recipe: self.recipe.clone(),
+            repeat,
+        })
+    }
+}
+
+/// The job being produced right now.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveJob {
+    pub job: ProductionJob,
+    pub timer: Timer,
+    /// What the job turned out to produce, rolled once its timer finishes.
+    pub produced: Option<Vec<InventoryItem>>,
+}
+
+/// Reasons a production job couldn't be queued or started.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProductionError {
+    QueueFull {
+        recipe: Recipe,
+    },
+    MissingMaterials {
+        recipe: Recipe,
+        missing: Vec<InventoryItem>,
+    },
+}
+
+/// A FIFO queue of production jobs for a single producer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionQueue {
+    pub jobs: VecDeque<ProductionJob>,
+    pub max_length: usize,
+    pub active: Option<ActiveJob>,
+    /// Inputs of the active job, taken out of the inventory when the job started.
+    pub reserved: Inventory,
+    /// The job at the front of the queue that last failed to start, so it's only reported once.
+    blocked: Option<ProductionJob>,
+}
+
+impl Default for ProductionQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUEUE_LENGTH)
+    }
+}
+
+impl ProductionQueue {
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            jobs: VecDeque::new(),
+            max_length,
+            active: None,
+            reserved: Inventory::unbounded(),
+            blocked: None,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.jobs.len() >= self.max_length
+    }
+
+    /// Adds a job to the back of the queue.
+    pub fn enqueue(&mut self, recipe: Recipe, repeat: JobRepeat) -> Result<(), ProductionError> {
+        if self.is_full() {
+            return Err(ProductionError::QueueFull { recipe });
+        }
+
+        if repeat != JobRepeat::Times(0) {
+            self.jobs.push_back(ProductionJob { recipe, repeat });
+        }
+        Ok(())
+    }
+
+    /// Removes a waiting job from the queue. Waiting jobs haven't taken any inputs yet.
+    pub fn cancel(&mut self, index: usize) -> Option<ProductionJob> {
+        self.jobs.remove(index)
+    }
+
+    /// Stops the active job, including any repeats it had left,
+    /// and returns its reserved inputs to the inventory.
+    pub fn cancel_active(&mut self, inventory: &mut Inventory, registry: &ItemRegistry) {
+        self.active = None;
+        self.reserved.transfer_all(inventory, registry);
+    }
+
+    /// Drops every job, including the active one, and returns the reserved inputs
+    /// to the inventory as far as they fit. Inputs that don't fit stay reserved.
+    pub fn wind_down(&mut self, inventory: &mut Inventory, registry: &ItemRegistry) {
+        self.jobs.clear();
+        self.blocked = None;
+        self.cancel_active(inventory, registry);
+    }
+
+    /// True if winding the queue down would change neither it nor the inventory.
+    pub fn is_wound_down(&self, inventory: &Inventory, registry: &ItemRegistry) -> bool {
+        self.jobs.is_empty()
+            && self.active.is_none()
+            && self
+                .reserved
+                .items
+                .iter()
+                .all(|item| inventory.fitting_amount(item, registry).is_zero())
+    }
+
+    /// Moves a waiting job to another position in the queue.
+    pub fn reorder(&mut self, from: usize, to: usize) {
+        if to >= self.jobs.len() {
+            return;
+        }
+
+        if let Some(job) = self.jobs.remove(from) {
+            self.jobs.insert(to, job);
+        }
+    }
+
+    /// True if ticking the queue has nothing to do but wait: it's empty, the job at the front
+    /// was already reported as blocked, or the inputs of a cancelled job are waiting for room.
+    pub fn is_waiting(&self) -> bool {
+        self.active.is_none()
+            && (!self.reserved.items.is_empty()
+                || self
+                    .jobs
+                    .front()
+                    .into_iter()
+                    .all(|job| self.blocked.as_ref() == Some(job)))
+    }
+
+    /// True if ticking the queue by `delta` may take items out of or put items into the inventory.
+    /// Never false when the tick would change the inventory.
+    pub fn moves_items(
+        &self,
+        delta: Duration,
+        inventory: &Inventory,
+        registry: &ItemRegistry,
+    ) -> bool {
+        let Some(active) = &self.active else {
+            if !self.reserved.items.is_empty() {
+                return self
+                    .reserved
+                    .items
+                    .iter()
+                    .any(|item| !inventory.fitting_amount(item, registry).is_zero());
+            }
+
+            return self
+                .jobs
+                .front()
+                .is_some_and(|job| inventory.has_items(job.recipe.items_required.clone()));
+        };
+
+        // Outputs are rolled when the job finishes, until then there's no telling whether they fit.
+        active.timer.remaining() <= delta
+            && active.produced.as_ref().into_iter().all(|produced| {
+                produced
+                    .iter()
+                    .all(|item| inventory.has_capacity_for(item, registry))
+            })
+    }
+
+    /// Returns how far along the active job is, from 0.0 to 1.0.
+    pub fn progress(&self) -> Option<f32> {
+        self.active.as_ref().map(|active| active.timer.fraction())
+    }
+
+    pub fn remaining_time(&self) -> Option<f32> {
+        self.active
+            .as_ref()
+            .map(|active| active.timer.remaining_secs())
+    }
+
+    /// Advances the active job, starting the next waiting job when idle.
+    /// Returns the recipe of a job that finished and whose outputs were added to the inventory,
+    /// or an error the first time the job at the front of the queue can't start.
+    pub fn tick(
+        &mut self,
+        delta: Duration,
+        inventory: &mut Inventory,
+        registry: &ItemRegistry,
+        rng: &mut impl Rng,
+    ) -> Result<Option<Recipe>, ProductionError> {
+        if self.active.is_none() {
+            self.start_next(inventory, registry)?;
+        }
+
+        let Some(active) = self.active.as_mut() else {
+            return Ok(None);
+        };
+        active.timer.tick(delta);
+
+        if !active.timer.finished() {
+            return Ok(None);
+        }
+
+        // Finished jobs wait here until there's room for everything they produced.
+        let produced = active
+            .produced
+            .get_or_insert_with(|| active.job.recipe.roll_outputs(rng))
+            .clone();
+
+        let mut output = Inventory::unbounded();
+        for item in produced.iter() {
+            output.add_to_inventory(item, registry);
+        }
+
+        if output
+            .transfer_items(inventory, &produced, registry)
+            .is_err()
+        {
+            return Ok(None);
+        }
+
+        self.reserved.items.clear();
+
+        let Some(finished) = self.active.take() else {
+            return Ok(None);
+        };
+
+        // Remaining runs go back to the front so they finish before later jobs start.
+        if let Some(next_run) = finished.job.next_run() {
+            self.jobs.push_front(next_run);
+        }
+
+        Ok(Some(finished.job.recipe))
+    }
+
+    /// Starts the job at the front of the queue by reserving all of its inputs at once.
+    fn start_next(
+        &mut self,
+        inventory: &mut Inventory,
+        registry: &ItemRegistry,
+    ) -> Result<(), ProductionError> {
+        // Inputs of a cancelled job that didn't fit back in the inventory are returned first.
+        if !self.reserved.items.is_empty() {
+            self.reserved.transfer_all(inventory, registry);
+            if !self.reserved.items.is_empty() {
+                return Ok(());
+            }
+        }
+
+        let Some(job) = self.jobs.front().cloned() else {
+            return Ok(());
+        };
+
+        if let Err(missing) =
+            inventory.transfer_items(&mut self.reserved, &job.recipe.items_required, registry)
+        {
+            if self.blocked.as_ref() == Some(&job) {
+                return Ok(());
+            }
+
+            self.blocked = Some(job.clone());
+            return Err(ProductionError::MissingMaterials {
+                recipe: job.recipe,
+                missing,
+            });
+        }
+
+        self.blocked = None;
+        self.jobs.pop_front();
+        self.active = Some(ActiveJob {
+            timer: Timer::from_seconds(job.recipe.time_required, TimerMode::Once),
+            produced: None,
+            job,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn test_station(ore: f32) -> Inventory {
+    Inventory {
+        items: vec![InventoryItem::new(
+            "iron_ore",
+            Amount::Weight(OrderedFloat(ore)),
+        )],
+        capacity: crate::inventory::components::Capacity {
+            maximum: OrderedFloat(1000.0),
+        },
+        slots: None,
+    }
+}
+
+#[cfg(test)]
+fn iron_recipe() -> Recipe {
+    Recipe {
+        id: "iron_ingot".to_string(),
+        items_required: vec![InventoryItem::new(
+            "iron_ore",
+            Amount::Weight(OrderedFloat(20.0)),
+        )],
+        outputs: vec![InventoryItem::new("iron_ingot", Amount::Quantity(1))],
+        byproducts: vec![],
+        time_required: 1.0,
+    }
+}
+
+#[test]
+fn test_reserved_inputs_cannot_back_two_jobs() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(20.0);
+    let mut first = ProductionQueue::default();
+    let mut second = ProductionQueue::default();
+
+    first.enqueue(iron_recipe(), JobRepeat::Times(1)).unwrap();
+    second.enqueue(iron_recipe(), JobRepeat::Times(1)).unwrap();
+
+    assert_eq!(
+        first.tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng()
+        ),
+        Ok(None)
+    );
+    assert!(matches!(
+        second.tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng()
+        ),
+        Err(ProductionError::MissingMaterials { .. })
+    ));
+    assert!(first.active.is_some());
+    assert!(second.active.is_none());
+    assert!(station.items.is_empty());
+}
+
+#[test]
+fn test_reserved_inputs_cannot_be_spent_mid_job() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(20.0);
+    let mut queue = ProductionQueue::default();
+
+    queue.enqueue(iron_recipe(), JobRepeat::Times(1)).unwrap();
+    queue
+        .tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng(),
+        )
+        .unwrap();
+
+    // Spending the ore elsewhere while the job runs has nothing left to take.
+    assert!(!station.remove_all_from_inventory(iron_recipe().items_required));
+
+    let finished = queue.tick(
+        Duration::from_secs(1),
+        &mut station,
+        &registry,
+        &mut rand::thread_rng(),
+    );
+    assert_eq!(finished, Ok(Some(iron_recipe())));
+    assert!(queue.reserved.items.is_empty());
+    assert!(station.has_items(iron_recipe().outputs));
+}
+
+#[test]
+fn test_duplicate_requirements_are_reserved_together() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(30.0);
+    let mut queue = ProductionQueue::default();
+
+    let mut recipe = iron_recipe();
+    recipe.items_required.push(recipe.items_required[0].clone());
+    queue.enqueue(recipe, JobRepeat::Times(1)).unwrap();
+
+    assert!(queue
+        .tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng()
+        )
+        .is_err());
+    assert!(queue.active.is_none());
+    assert!(queue.reserved.items.is_empty());
+    assert!(station.has_items(vec![InventoryItem::new(
+        "iron_ore",
+        Amount::Weight(OrderedFloat(30.0)),
+    )]));
+}
+
+#[test]
+fn test_blocked_queue_waits_without_touching_the_inventory() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(10.0);
+    let mut queue = ProductionQueue::default();
+    assert!(queue.is_waiting());
+
+    queue.enqueue(iron_recipe(), JobRepeat::Times(1)).unwrap();
+    assert!(!queue.is_waiting());
+    assert!(!queue.moves_items(Duration::ZERO, &station, &registry));
+
+    // Reported once, then the queue just waits for the ore.
+    assert!(queue
+        .tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng()
+        )
+        .is_err());
+    assert!(queue.is_waiting());
+
+    assert!(station.add_to_inventory(
+        &InventoryItem::new("iron_ore", Amount::Weight(OrderedFloat(10.0))),
+        &registry,
+    ));
+    assert!(queue.moves_items(Duration::ZERO, &station, &registry));
+}
+
+#[test]
+fn test_cancel_returns_reserved_inputs() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(20.0);
+    let mut queue = ProductionQueue::default();
+
+    queue.enqueue(iron_recipe(), JobRepeat::Forever).unwrap();
+    queue
+        .tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng(),
+        )
+        .unwrap();
+    assert!(station.items.is_empty());
+
+    queue.cancel_active(&mut station, &registry);
+
+    assert!(queue.active.is_none());
+    assert!(queue.reserved.items.is_empty());
+    assert!(station.has_items(iron_recipe().items_required));
+}
+
+#[test]
+fn test_finished_job_produces_outputs_and_byproducts() {
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(20.0);
+    let mut queue = ProductionQueue::default();
+
+    let mut recipe = iron_recipe();
+    recipe.outputs.push(InventoryItem::new(
+        "iron_ore",
+        Amount::Weight(OrderedFloat(5.0)),
+    ));
+    recipe.byproducts = vec![
+        crate::recipes::components::Byproduct {
+            item: InventoryItem::new("iron_ingot", Amount::Quantity(2)),
+            chance: 1.0,
+        },
+        crate::recipes::components::Byproduct {
+            item: InventoryItem::new("iron_ingot", Amount::Quantity(100)),
+            chance: 0.0,
+        },
+    ];
+    queue.enqueue(recipe.clone(), JobRepeat::Times(1)).unwrap();
+
+    let mut rng = rand::thread_rng();
+    queue
+        .tick(Duration::ZERO, &mut station, &registry, &mut rng)
+        .unwrap();
+    let finished = queue.tick(Duration::from_secs(1), &mut station, &registry, &mut rng);
+
+    assert_eq!(finished, Ok(Some(recipe)));
+    assert_eq!(
+        station.get(&"iron_ingot".into()).map(|item| item.amount()),
+        Some(Amount::Quantity(3))
+    );
+    assert_eq!(
+        station.get(&"iron_ore".into()).map(|item| item.amount()),
+        Some(Amount::Weight(OrderedFloat(5.0)))
+    );
+}
+
+#[test]
+fn test_winding_down_into_a_partly_full_station_keeps_the_rest_reserved() {
+    use crate::items::components::ItemId;
+
+    let registry = ItemRegistry::iron_for_tests();
+    let mut station = test_station(40.0);
+    let mut queue = ProductionQueue::default();
+
+    let mut recipe = iron_recipe();
+    recipe.items_required = vec![InventoryItem::new(
+        "iron_ore",
+        Amount::Weight(OrderedFloat(40.0)),
+    )];
+    queue.enqueue(recipe, JobRepeat::Times(2)).unwrap();
+    queue
+        .tick(
+            Duration::ZERO,
+            &mut station,
+            &registry,
+            &mut rand::thread_rng(),
+        )
+        .unwrap();
+    assert!(station.items.is_empty());
+
+    // Only room for 15 of the 40 kg that were reserved.
+    station.capacity.maximum = OrderedFloat(15.0);
+    queue.wind_down(&mut station, &registry);
+
+    let ore = |inventory: &Inventory| {
+        inventory
+            .get(&ItemId::from("iron_ore"))
+            .map(|item| item.amount())
+    };
+    assert!(queue.jobs.is_empty());
+    assert!(queue.active.is_none());
+    assert_eq!(ore(&station), Some(Amount::Weight(OrderedFloat(15.0))));
+    assert_eq!(
+        ore(&queue.reserved),
+        Some(Amount::Weight(OrderedFloat(25.0)))
+    );
+    assert!(queue.is_wound_down(&station, &registry));
+
+    station.capacity.maximum = OrderedFloat(1000.0);
+    assert!(!queue.is_wound_down(&station, &registry));
+    queue.wind_down(&mut station, &registry);
+    assert_eq!(ore(&station), Some(Amount::Weight(OrderedFloat(40.0))));
+    assert!(queue.reserved.items.is_empty());
+}
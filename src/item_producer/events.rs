@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::recipes::components::Recipe;
+
+use super::components::{JobRepeat, ProductionError};
+
+/// Sent when a job can't be queued or started on a producer.
+#[derive(Event, Debug, Clone)]
+pub struct ProductionErrorEvent {
+    pub entity: Entity,
+    pub error: ProductionError,
+}
+
+/// Changes to the jobs queued on a producer entity.
+#[derive(Event, Debug, Clone)]
+pub enum ProductionQueueEvent {
+    Enqueue {
+        entity: Entity,
+        recipe: Recipe,
+        repeat: JobRepeat,
+    },
+    Cancel {
+        entity: Entity,
+        index: usize,
+    },
+    CancelActive {
+        entity: Entity,
+    },
+    Reorder {
+        entity: Entity,
+        from: usize,
+        to: usize,
+    },
+}
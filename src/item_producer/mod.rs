@@ -0,0 +1,30 @@
+pub mod components;
+pub mod events;
+pub mod plugin;
+pub mod systems;
+
+use crate::recipes::components::Recipe;
+
+use components::ProductionQueue;
+
+/// Anything that turns items into other items through a production queue.
+pub trait ItemProducer {
+    fn recipes(&self) -> Vec<Recipe>;
+    fn queue(&self) -> &ProductionQueue;
+    fn queue_mut(&mut self) -> &mut ProductionQueue;
+
+    fn currently_processing(&self) -> Option<Recipe> {
+        self.queue()
+            .active
+            .as_ref()
+            .map(|active| active.job.recipe.clone())
+    }
+
+    fn remaining_processing_percent(&self) -> Option<f32> {
+        self.queue().progress()
+    }
+
+    fn remaining_processing_time(&self) -> Option<f32> {
+        self.queue().remaining_time()
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::{
+    components::Producer,
+    events::{ProductionErrorEvent, ProductionQueueEvent},
+    systems::{
+        attach_producers_to_modules, on_production_queue_event, refresh_producer_recipes,
+        update_production_queues,
+    },
+};
+
+pub struct ItemProducerPlugin;
+
+impl Plugin for ItemProducerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ProductionQueueEvent>()
+            .add_event::<ProductionErrorEvent>()
+            .add_systems(
+                Update,
+                (
+                    refresh_producer_recipes,
+                    on_production_queue_event::<Producer>,
+                    // After the queue events, so a wound down producer never starts a new job.
+                    attach_producers_to_modules,
+                    update_production_queues::<Producer>,
+                )
+                    .chain(),
+            );
+    }
+}
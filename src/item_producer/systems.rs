@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+
+use crate::{
+    inventory::components::Inventory,
+    items::resources::ItemRegistry,
+    recipes::{components::Recipe, resources::Recipes},
+    research::resources::ResearchUnlocks,
+    space_station::{components::SpaceStation, modules::components::SpaceStationModuleType},
+    world_seed::resources::{RngStream, SeededRng},
+};
+
+use super::{
+    components::{Producer, ProducerKind},
+    events::{ProductionErrorEvent, ProductionQueueEvent},
+    ItemProducer,
+};
+
+/// Applies queue events to producers of type `T`, refunding the inputs of cancelled jobs.
+pub fn on_production_queue_event<T: Component + ItemProducer>(
+    mut reader: EventReader<ProductionQueueEvent>,
+    mut producer_query: Query<&mut T>,
+    mut station_query: Query<&mut Inventory, With<SpaceStation>>,
+    mut error_events: EventWriter<ProductionErrorEvent>,
+    item_registry: Res<ItemRegistry>,
+) {
+    let Ok(mut inventory) = station_query.get_single_mut() else {
+        return;
+    };
+
+    for event in reader.read() {
+        match event {
+            ProductionQueueEvent::Enqueue {
+                entity,
+                recipe,
+                repeat,
+            } => {
+                if let Ok(mut producer) = producer_query.get_mut(*entity) {
+                    if let Err(error) = producer.queue_mut().enqueue(recipe.clone(), *repeat) {
+                        error_events.send(ProductionErrorEvent {
+                            entity: *entity,
+                            error,
+                        });
+                    }
+                }
+            }
+            ProductionQueueEvent::Cancel { entity, index } => {
+                if let Ok(mut producer) = producer_query.get_mut(*entity) {
+                    producer.queue_mut().cancel(*index);
+                }
+            }
+            ProductionQueueEvent::CancelActive { entity } => {
+                if let Ok(mut producer) = producer_query.get_mut(*entity) {
+                    producer
+                        .queue_mut()
+                        .cancel_active(&mut inventory, &item_registry);
+                }
+            }
+            ProductionQueueEvent::Reorder { entity, from, to } => {
+                if let Ok(mut producer) = producer_query.get_mut(*entity) {
+                    producer.queue_mut().reorder(*from, *to);
+                }
+            }
+        }
+    }
+}
+
+/// Ticks the production queues of every producer of type `T`.
+/// Producers take their inputs from and deliver their outputs to the space station's inventory.
+pub fn update_production_queues<T: Component + ItemProducer>(
+    mut producer_query: Query<(Entity, &mut T)>,
+    mut station_query: Query<&mut Inventory, With<SpaceStation>>,
+    mut error_events: EventWriter<ProductionErrorEvent>,
+    time: Res<Time>,
+    item_registry: Res<ItemRegistry>,
+    mut seeded_rng: ResMut<SeededRng>,
+) {
+    let Ok(mut inventory) = station_query.get_single_mut() else {
+        return;
+    };
+
+    let rng = seeded_rng.stream(RngStream::Byproducts);
+
+    for (entity, mut producer) in producer_query.iter_mut() {
+        let moves_items = producer
+            .queue()
+            .moves_items(time.delta(), &inventory, &item_registry);
+
+        // Leave idle and blocked producers untouched, so neither they nor the inventory
+        // show up as changed every frame.
+        if !moves_items && producer.queue().is_waiting() {
+            continue;
+        }
+
+        let station_inventory = if moves_items {
+            &mut *inventory
+        } else {
+            inventory.bypass_change_detection()
+        };
+
+        if let Err(error) =
+            producer
+                .queue_mut()
+                .tick(time.delta(), station_inventory, &item_registry, rng)
+        {
+            error_events.send(ProductionErrorEvent { entity, error });
+        }
+    }
+}
+
+/// Adds a producer to station modules built as a refinery or factory, and winds it down
+/// when the module is rebuilt as something else, returning any inputs it had reserved.
+/// Inputs that don't fit back into the station inventory are never dropped: a new producer
+/// hands them back once there's room, and a module that no longer produces anything keeps
+/// its old, idle producer and retries every frame until they fit.
+pub fn attach_producers_to_modules(
+    mut commands: Commands,
+    mut module_query: Query<
+        (Entity, &SpaceStationModuleType, Option<&mut Producer>),
+        Or<(Changed<SpaceStationModuleType>, With<Producer>)>,
+    >,
+    mut station_query: Query<&mut Inventory, With<SpaceStation>>,
+    item_registry: Res<ItemRegistry>,
+    recipes: Res<Recipes>,
+    unlocks: Res<ResearchUnlocks>,
+) {
+    let mut station = station_query.get_single_mut().ok();
+
+    for (entity, module_type, producer) in module_query.iter_mut() {
+        let kind = ProducerKind::from_module_type(*module_type);
+
+        let Some(mut producer) = producer else {
+            if let Some(kind) = kind {
+                commands.entity(entity).insert(Producer::new(
+                    kind,
+                    available_recipes(kind, &recipes, &unlocks),
+                ));
+            }
+            continue;
+        };
+
+        if Some(producer.kind) == kind {
+            continue;
+        }
+
+        // Without a station there's nowhere to return the inputs to yet.
+        let Some(inventory) = station.as_mut() else {
+            continue;
+        };
+
+        if !producer.queue.is_wound_down(inventory, &item_registry) {
+            producer.queue.wind_down(inventory, &item_registry);
+        }
+
+        match kind {
+            Some(kind) => {
+                let mut new_producer =
+                    Producer::new(kind, available_recipes(kind, &recipes, &unlocks));
+                new_producer.queue.reserved =
+                    std::mem::replace(&mut producer.queue.reserved, Inventory::unbounded());
+                commands.entity(entity).insert(new_producer);
+            }
+            None if producer.queue.reserved.items.is_empty() => {
+                commands.entity(entity).remove::<Producer>();
+            }
+            None => {}
+        }
+    }
+}
+
+/// Gives every producer the latest recipes for its kind when the recipe book is reloaded
+/// or research unlocks new ones. Jobs that are already queued keep the recipe they were queued with.
+pub fn refresh_producer_recipes(
+    recipes: Res<Recipes>,
+    unlocks: Res<ResearchUnlocks>,
+    mut producer_query: Query<&mut Producer>,
+) {
+    if !(recipes.is_changed() || unlocks.is_changed()) {
+        return;
+    }
+
+    for mut producer in producer_query.iter_mut() {
+        producer.recipes = available_recipes(producer.kind, &recipes, &unlocks);
+    }
+}
+
+/// Returns the recipes of the given kind of producer that have been researched.
+fn available_recipes(
+    kind: ProducerKind,
+    recipes: &Recipes,
+    unlocks: &ResearchUnlocks,
+) -> Vec<Recipe> {
+    recipes
+        .for_producer(kind)
+        .into_iter()
+        .filter(|recipe| unlocks.is_recipe_unlocked(&recipe.id))
+        .collect()
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use bevy::{prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use crate::ron_asset::loader::RonAsset;
+
+/// Unique identifier of an item, as declared in the item definitions asset.
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct ItemId(pub String);
+
+impl ItemId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for ItemId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl fmt::Debug for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+pub enum ItemCategory {
+    #[default]
+    Ore,
+    Ingot,
+    Component,
+    Byproduct,
+}
+
+/// How an item is measured when it sits in an inventory.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum Stacking {
+    /// Loose material tracked in kilograms, ie. ore.
+    #[default]
+    Weight,
+    /// Discrete units tracked by count, ie. ingots and components.
+    Quantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ItemDefinition {
+    pub id: ItemId,
+    pub name: String,
+    pub category: ItemCategory,
+    /// Weight in kilograms of a single unit of this item.
+    pub unit_weight: f32,
+    pub stacking: Stacking,
+    /// Maximum amount of this item that fits in a single stack, if limited.
+    #[serde(default)]
+    pub max_stack: Option<f32>,
+    /// Trade value in credits of a single unit of this item.
+    #[serde(default)]
+    pub value: f32,
+}
+
+/// The contents of an `*.items.ron` asset file.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct ItemDefinitions {
+    pub items: Vec<ItemDefinition>,
+}
+
+impl RonAsset for ItemDefinitions {
+    const EXTENSIONS: &'static [&'static str] = &["items.ron"];
+}
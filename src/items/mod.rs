@@ -1,19 +1,13 @@
-use std::{
-    fmt,
-    ops::{AddAssign, SubAssign},
-};
+pub mod components;
+pub mod plugin;
+pub mod resources;
+pub mod systems;
 
-use ordered_float::OrderedFloat;
+use std::{cmp::Ordering, fmt};
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Hash)]
-pub enum MetalIngot {
-    #[default]
-    IronIngot,
-    SilverIngot,
-    GoldIngot,
-}
+use ordered_float::OrderedFloat;
 
-#[derive(Default, Clone, PartialEq, PartialOrd, Hash)]
+#[derive(Default, Clone, PartialEq, Hash)]
 pub enum Amount {
     #[default]
     None,
@@ -21,6 +15,58 @@ pub enum Amount {
     Quantity(u32),
 }
 
+impl Amount {
+    /// Returns true if there is nothing left of this amount.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Amount::Weight(w) => w.0 <= 0.0,
+            Amount::Quantity(q) => *q == 0,
+            Amount::None => true,
+        }
+    }
+
+    /// Returns the amount as a plain number of units, ie. kilograms or item count.
+    pub fn units(&self) -> f32 {
+        match self {
+            Amount::Weight(w) => w.0,
+            Amount::Quantity(q) => *q as f32,
+            Amount::None => 0.0,
+        }
+    }
+
+    /// Returns the sum of both amounts, or None if they are of different kinds.
+    pub fn checked_add(&self, rhs: &Amount) -> Option<Amount> {
+        match (self, rhs) {
+            (Amount::Weight(a), Amount::Weight(b)) => Some(Amount::Weight(*a + *b)),
+            (Amount::Quantity(a), Amount::Quantity(b)) => a.checked_add(*b).map(Amount::Quantity),
+            (Amount::None, amount) | (amount, Amount::None) => Some(amount.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns what's left after taking `rhs` away, or None if they are of different kinds
+    /// or there isn't enough.
+    pub fn checked_sub(&self, rhs: &Amount) -> Option<Amount> {
+        match (self, rhs) {
+            (Amount::Weight(a), Amount::Weight(b)) if a >= b => Some(Amount::Weight(*a - *b)),
+            (Amount::Quantity(a), Amount::Quantity(b)) => a.checked_sub(*b).map(Amount::Quantity),
+            (amount, Amount::None) => Some(amount.clone()),
+            (Amount::None, amount) if amount.is_zero() => Some(Amount::None),
+            _ => None,
+        }
+    }
+
+    /// Returns an amount of the same kind holding the given number of units.
+    /// Quantities are rounded down to whole units.
+    pub fn with_units(&self, units: f32) -> Amount {
+        match self {
+            Amount::Weight(_) => Amount::Weight(OrderedFloat(units.max(0.0))),
+            Amount::Quantity(_) => Amount::Quantity(units.max(0.0).floor() as u32),
+            Amount::None => Amount::None,
+        }
+    }
+}
+
 impl fmt::Debug for Amount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -37,38 +83,17 @@ impl fmt::Debug for Amount {
     }
 }
 
-impl AddAssign for Amount {
-    fn add_assign(&mut self, rhs: Self) {
-        match self {
-            Amount::Weight(weight) => {
-                if let Amount::Weight(w) = rhs {
-                    *weight += w
-                }
-            }
-            Amount::Quantity(quantity) => {
-                if let Amount::Quantity(q) = rhs {
-                    *quantity += q
-                }
-            }
-            Amount::None => {}
-        }
-    }
-}
-
-impl SubAssign for Amount {
-    fn sub_assign(&mut self, rhs: Self) {
-        match self {
-            Amount::Weight(weight) => {
-                if let Amount::Weight(w) = rhs {
-                    *weight -= w
-                }
-            }
-            Amount::Quantity(quantity) => {
-                if let Amount::Quantity(q) = rhs {
-                    *quantity -= q
-                }
-            }
-            Amount::None => {}
+/// Amounts of different kinds can't be compared, so neither of them covers the other.
+/// `Amount::None` is less than any amount of either kind.
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Amount::Weight(a), Amount::Weight(b)) => a.partial_cmp(b),
+            (Amount::Quantity(a), Amount::Quantity(b)) => a.partial_cmp(b),
+            (Amount::None, Amount::None) => Some(Ordering::Equal),
+            (Amount::None, _) => Some(Ordering::Less),
+            (_, Amount::None) => Some(Ordering::Greater),
+            _ => None,
         }
     }
 }
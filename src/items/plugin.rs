@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::ron_asset::{loader::RonAssetLoader, systems::rebuild_from_asset};
+
+use super::{components::ItemDefinitions, resources::ItemRegistry, systems::load_item_definitions};
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ItemDefinitions>()
+            .init_asset_loader::<RonAssetLoader<ItemDefinitions>>()
+            .init_resource::<ItemRegistry>()
+            .add_systems(PreStartup, load_item_definitions)
+            .add_systems(
+                PreUpdate,
+                rebuild_from_asset::<ItemDefinitions, ItemRegistry>,
+            );
+    }
+}
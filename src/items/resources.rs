@@ -0,0 +1,134 @@
+use bevy::{prelude::*, utils::HashMap};
+use ordered_float::OrderedFloat;
+
+use crate::{inventory::components::InventoryItem, items::Amount, ron_asset::systems::FromAsset};
+
+use super::components::{ItemCategory, ItemDefinition, ItemDefinitions, ItemId, Stacking};
+
+pub const ITEM_DEFINITIONS_PATH: &str = "data/core.items.ron";
+
+#[derive(Resource)]
+pub struct ItemDefinitionsHandle(pub Handle<ItemDefinitions>);
+
+/// Lookup table of every item known to the game, built from the item definitions asset.
+#[derive(Resource, Default, Debug)]
+pub struct ItemRegistry {
+    definitions: HashMap<ItemId, ItemDefinition>,
+}
+
+impl FromAsset<ItemDefinitions> for ItemRegistry {
+    fn from_asset(definitions: &ItemDefinitions) -> Self {
+        Self::from_definitions(definitions)
+    }
+}
+
+impl ItemRegistry {
+    pub fn from_definitions(definitions: &ItemDefinitions) -> Self {
+        Self {
+            definitions: definitions
+                .items
+                .iter()
+                .map(|definition| (definition.id.clone(), definition.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, id: &ItemId) -> Option<&ItemDefinition> {
+        self.definitions.get(id)
+    }
+
+    pub fn contains(&self, id: &ItemId) -> bool {
+        self.definitions.contains_key(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ItemDefinition> {
+        self.definitions.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Returns an amount of the item in the way it stacks, ie. kilograms or whole units.
+    pub fn amount_of(&self, id: &ItemId, units: f32) -> Amount {
+        match self.get(id).map(|definition| definition.stacking) {
+            Some(Stacking::Quantity) => Amount::Quantity(units.max(0.0) as u32),
+            _ => Amount::Weight(OrderedFloat(units)),
+        }
+    }
+
+    /// Weight in kilograms of a single unit of the item. Unregistered items are weightless.
+    pub fn unit_weight(&self, id: &ItemId) -> f32 {
+        self.get(id)
+            .map(|definition| definition.unit_weight)
+            .unwrap_or(0.0)
+    }
+
+    /// Total weight in kilograms of the given amount of an item.
+    pub fn weight_of(&self, item: &InventoryItem) -> f32 {
+        let unit_weight = self.unit_weight(item.id());
+
+        match item.amount() {
+            Amount::Weight(w) => w.0 * unit_weight,
+            Amount::Quantity(q) => q as f32 * unit_weight,
+            Amount::None => 0.0,
+        }
+    }
+
+    /// Maximum number of units of the item in a single stack. None means unlimited.
+    pub fn max_stack(&self, id: &ItemId) -> Option<f32> {
+        self.get(id).and_then(|definition| definition.max_stack)
+    }
+
+    pub fn category(&self, id: &ItemId) -> Option<ItemCategory> {
+        self.get(id).map(|definition| definition.category)
+    }
+
+    /// Total trade value in credits of the given amount of an item.
+    pub fn value_of(&self, item: &InventoryItem) -> f32 {
+        self.get(item.id())
+            .map(|definition| definition.value * item.amount().units())
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the display name of the item, falling back to its id if it isn't registered.
+    pub fn name(&self, id: &ItemId) -> String {
+        self.get(id)
+            .map(|definition| definition.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Formats an inventory item for display, ie. "Iron Ore: 12.5 Kgs".
+    pub fn describe(&self, item: &InventoryItem) -> String {
+        format!("{}: {:?}", self.name(item.id()), item.amount())
+    }
+}
+
+#[cfg(test)]
+impl ItemRegistry {
+    /// Builds a registry of test items from (id, stacking, unit weight, max stack) entries.
+    pub fn for_tests(items: &[(&str, Stacking, f32, Option<f32>)]) -> Self {
+        Self::from_definitions(&ItemDefinitions {
+            items: items
+                .iter()
+                .map(|(id, stacking, unit_weight, max_stack)| ItemDefinition {
+                    id: ItemId::from(*id),
+                    name: id.to_string(),
+                    category: ItemCategory::Component,
+                    unit_weight: *unit_weight,
+                    stacking: *stacking,
+                    max_stack: *max_stack,
+                    value: 1.0,
+                })
+                .collect(),
+        })
+    }
+
+    /// A registry of iron ore by weight and iron ingots by quantity, both 1 kg a unit.
+    pub fn iron_for_tests() -> Self {
+        Self::for_tests(&[
+            ("iron_ore", Stacking::Weight, 1.0, None),
+            ("iron_ingot", Stacking::Quantity, 1.0, None),
+        ])
+    }
+}
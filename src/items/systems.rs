@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use super::resources::{ItemDefinitionsHandle, ITEM_DEFINITIONS_PATH};
+
+pub fn load_item_definitions(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ItemDefinitionsHandle(
+        asset_server.load(ITEM_DEFINITIONS_PATH),
+    ));
+}
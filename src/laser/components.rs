@@ -1,4 +1,11 @@
 use bevy::prelude::Component;
 
+/// Laser energy put into whatever the beam hits, per second.
+pub const LASER_POWER: f32 = 100.0;
+/// Thermal damage per unit of laser energy, before the target's resistances.
+pub const LASER_DAMAGE: f32 = 1.0;
+
 #[derive(Component)]
-pub struct Laser;
+pub struct Laser {
+    pub power: f32,
+}
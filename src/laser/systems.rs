@@ -6,12 +6,15 @@ use bevy::utils::hashbrown::HashSet;
 use bevy_hanabi::{EffectInitializers, EffectProperties};
 // use bevy_particle_systems::Playing;
 
-use super::components::Laser;
+use super::components::{Laser, LASER_DAMAGE, LASER_POWER};
 use super::events::LaserEvent;
 
 use crate::particles::components::ProjectileImpactParticles;
 use crate::player::components::Player;
-use crate::{asteroid::events::AblateEvent, health::events::DamageEvent};
+use crate::{
+    asteroid::events::AblateEvent,
+    health::{components::DamageType, events::DamageEvent},
+};
 
 pub fn setup_laser(mut commands: Commands, mut laser_query: Query<&mut Laser>) {
     // let line = shapes::Line(Vec2::ZERO, Vec2::X);
@@ -19,7 +22,7 @@ pub fn setup_laser(mut commands: Commands, mut laser_query: Query<&mut Laser>) {
     // Create Laser if it Doesn't Exist
     let Ok(_laser) = laser_query.get_single_mut() else {
         commands
-            .spawn(Laser)
+            .spawn(Laser { power: LASER_POWER })
             .insert((Transform::from_xyz(0.0, 0.0, 1.0), Name::new("Laser")));
         return;
     };
@@ -30,6 +33,8 @@ pub fn fire_laser_raycasting(
     mut laser_event_reader: EventReader<LaserEvent>,
     mut ablate_event_writer: EventWriter<AblateEvent>,
     player_q: Query<Entity, With<Player>>,
+    laser_query: Query<&Laser>,
+    time: Res<Time>,
     spatial_query: SpatialQuery,
     mut damage_events: EventWriter<DamageEvent>,
     mut gizmos: Gizmos,
@@ -44,6 +49,9 @@ pub fn fire_laser_raycasting(
 ) {
 
     let player_ent = player_q.single();
+    let Ok(laser) = laser_query.get_single() else {
+        return;
+    };
 
     // Exclude Player from Raycasting
     let excluded_entities: HashSet<Entity, EntityHash> = vec![player_ent].into_iter().collect();
@@ -72,15 +80,20 @@ pub fn fire_laser_raycasting(
 
                 gizmos.line_2d(ray_pos, hit_point.as_vec2(), Color::from(RED));
 
+                let energy = laser.power * time.delta_secs();
+
                 commands.trigger(AblateEvent(
                     hit_ent,
                     hit_point.as_vec2(),
                     hit_normal.as_vec2(),
+                    energy,
                 ));
-                
+
                 damage_events.send(DamageEvent {
                     entity: hit_ent,
-                    damage: 5.0,
+                    damage: energy * LASER_DAMAGE,
+                    damage_type: DamageType::Thermal,
+                    source: Some(player_ent),
                 });
 
                 // Note: On first frame where the effect spawns, EffectSpawner is spawned during
@@ -1,12 +1,11 @@
 pub(crate) mod ai;
 pub(crate) mod asteroid;
+pub(crate) mod asteroid_field;
 pub(crate) mod background;
 pub(crate) mod battery;
 pub(crate) mod camera;
 pub(crate) mod collectible;
 pub(crate) mod crosshair;
-pub(crate) mod events;
-pub(crate) mod factory;
 pub(crate) mod health;
 pub(crate) mod hexgrid;
 pub(crate) mod inventory;
@@ -18,11 +17,15 @@ pub(crate) mod player;
 pub(crate) mod player_input;
 pub(crate) mod projectile;
 pub(crate) mod rcs;
-pub(crate) mod recipe;
-pub(crate) mod refinery;
+pub(crate) mod recipes;
+pub(crate) mod research;
+pub(crate) mod ron_asset;
+pub(crate) mod scanner;
 pub(crate) mod space_station;
 pub(crate) mod ui;
 pub(crate) mod upgrades;
+pub(crate) mod waves;
+pub(crate) mod world_seed;
 
 // #![feature(array_methods)]
 
@@ -38,24 +41,31 @@ use bevy::{
 use avian2d::prelude::*;
 use bevy_hanabi::HanabiPlugin;
 use camera::plugin::GameCameraPlugin;
-use factory::FactoryPlugin;
+use collectible::plugin::CollectiblesPlugin;
 use inventory::plugin::InventoryPlugin;
+use item_producer::plugin::ItemProducerPlugin;
+use items::plugin::ItemsPlugin;
 use particles::plugin::ParticlePlugin;
 use player::plugin::PlayerPlugin;
 use player_input::plugin::PlayerInputPlugin;
 use projectile::plugin::ProjectilePlugin;
 use rcs::plugin::RCSPlugin;
+use recipes::plugin::RecipesPlugin;
+use research::plugin::ResearchPlugin;
+use scanner::plugin::ScannerPlugin;
 use ui::plugin::GameUIPlugin;
 // use projectile::ProjectilePlugin;
 use crate::crosshair::plugin::CrosshairPlugin;
 use crate::laser::plugin::LaserPlugin;
 use asteroid::plugin::AsteroidPlugin;
+use asteroid_field::plugin::AsteroidFieldPlugin;
 use battery::plugin::BatteryPlugin;
 use health::plugin::HealthPlugin;
 use hexgrid::plugin::HexBasePlugin;
-use refinery::RefineryPlugin;
 use space_station::plugin::SpaceStationPlugin;
 use upgrades::plugin::UpgradesPlugin;
+use waves::plugin::WavesPlugin;
+use world_seed::plugin::WorldSeedPlugin;
 
 // Defines the amount of time that should elapse between each physics step.
 // const TIME_STEP: f32 = 1.0 / 60.0;
@@ -101,9 +111,9 @@ fn main() {
             PlayerInputPlugin,
             InventoryPlugin,
             SpaceStationPlugin,
-            RefineryPlugin,
-            FactoryPlugin,
+            ItemProducerPlugin,
             AsteroidPlugin,
+            CollectiblesPlugin,
             LaserPlugin,
             CrosshairPlugin,
             GameUIPlugin,
@@ -111,9 +121,16 @@ fn main() {
             GameCameraPlugin,
         ))
         .add_plugins((
+            WorldSeedPlugin,
+            AsteroidFieldPlugin,
+            ItemsPlugin,
+            RecipesPlugin,
+            ResearchPlugin,
             HealthPlugin,
             BatteryPlugin,
+            ScannerPlugin,
             AiPlugin,
+            WavesPlugin,
             ProjectilePlugin,
             BackgroundPlugin,
         ))
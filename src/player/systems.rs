@@ -9,11 +9,16 @@ use super::components::Player;
 use super::resources::EmptyInventoryDepositTimer;
 
 use crate::camera::components::CameraTarget;
-use crate::health::components::Health;
-use crate::inventory::components::{Capacity, Inventory};
+use crate::collectible::components::TractorBeam;
+use crate::health::components::{Health, Shield};
+use crate::inventory::components::{Capacity, Inventory, SlotGrid};
 use crate::inventory::systems::attach_inventory_to_entity;
+use crate::items::resources::ItemRegistry;
 use crate::laser::events::LaserEvent;
 use crate::player_input::resources::MouseWorldPosition;
+use crate::projectile::components::Faction;
+use crate::research::{components::Unlock, resources::ResearchUnlocks};
+use crate::scanner::components::Scanner;
 use crate::space_station::components::SpaceStation;
 use crate::ui::context_clue::resources::{ContextClue, ContextClues};
 use crate::upgrades::{components::UpgradesComponent, events::UpgradeEvent};
@@ -26,16 +31,24 @@ use crate::{
     rcs::{components::RCSBooster, events::RCSThrustVectorEvent},
 };
 
+/// Number of cargo slots on the ship before any cargo bay upgrades.
+pub const PLAYER_CARGO_SLOTS: usize = 8;
+
 pub fn spawn_player(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    item_registry: Res<ItemRegistry>,
 ) {
     let player_poly = RegularPolygon::new(20.0, 3);
 
     let player = commands
         .spawn(Player::new())
-        .insert((Name::new("Player"), UpgradesComponent::new()))
+        .insert((
+            Name::new("Player"),
+            UpgradesComponent::new(),
+            Faction::Player,
+        ))
         .insert(CameraTarget)
         .insert((
             RigidBody::Dynamic,
@@ -55,8 +68,11 @@ pub fn spawn_player(
             )
             .unwrap(),
             Health::new(),
+            Shield::with_maximum(50.0, 5.0),
             Battery::new(),
             RCSBooster::new(),
+            Scanner::default(),
+            TractorBeam::default(),
         ))
         .insert((
             Mesh2d(meshes.add(player_poly)),
@@ -72,8 +88,10 @@ pub fn spawn_player(
             capacity: Capacity {
                 maximum: OrderedFloat(200.0),
             },
+            slots: Some(SlotGrid::new(PLAYER_CARGO_SLOTS)),
         },
         player,
+        &item_registry,
     );
 }
 
@@ -220,11 +238,14 @@ pub fn display_empty_ship_inventory_context_clue(
 // TODO: Refector Collectibles into Module?
 
 /// Updates the player mass with the ship's net mass for physics engine.
-pub fn update_player_mass(mut player_query: Query<(&Player, &Inventory, &mut Mass)>) {
+pub fn update_player_mass(
+    mut player_query: Query<(&Player, &Inventory, &mut Mass)>,
+    item_registry: Res<ItemRegistry>,
+) {
     const PLAYER_MASS: f32 = 1000.0;
 
     for (_player, inventory, mut mass) in player_query.iter_mut() {
-        let inventory_weight = inventory.gross_material_weight();
+        let inventory_weight = inventory.gross_material_weight(&item_registry);
         mass.0 = (inventory_weight + PLAYER_MASS).0;
     }
 }
@@ -247,16 +268,25 @@ pub fn ship_battery_is_empty_context_clue(
 pub fn on_upgrade_event(
     mut reader: EventReader<UpgradeEvent>,
     mut base_station_query: Query<(&SpaceStation, &mut Inventory), With<SpaceStation>>,
-    mut player_query: Query<(&mut Player, &mut UpgradesComponent), Without<SpaceStation>>, // mut refinery_timer: ResMut<RefineryTimer>,
+    mut player_query: Query<
+        (&mut Player, &mut UpgradesComponent, &mut Inventory),
+        Without<SpaceStation>,
+    >, // mut refinery_timer: ResMut<RefineryTimer>,
+    unlocks: Res<ResearchUnlocks>,
 ) {
     for event in reader.read() {
         println!("Upgrade Event Detected!");
         let (_base_station, mut inventory) = base_station_query.single_mut();
-        let (mut player, mut upgrades) = player_query.single_mut();
+        let (mut player, mut upgrades, mut ship_inventory) = player_query.single_mut();
 
         let upgrade = event.0;
         println!("{:?}", upgrade);
 
-        upgrades.upgrade(upgrade, &mut player, &mut inventory);
+        if !unlocks.is_unlocked(&Unlock::Upgrade(upgrade.next())) {
+            warn!("{:?} has not been researched yet.", upgrade.next());
+            continue;
+        }
+
+        upgrades.upgrade(upgrade, &mut player, &mut inventory, &mut ship_inventory);
     }
 }
@@ -1,6 +1,132 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use crate::{health::components::DamageType, ron_asset::loader::RonAsset};
 
 #[derive(Component)]
 pub struct Projectile {
     pub timer: Timer,
+    /// The entity that fired the projectile, which it passes through.
+    pub source: Entity,
+    /// Projectiles pass through everything on the shooter's side.
+    pub faction: Option<Faction>,
+    pub damage: f32,
+    pub damage_type: DamageType,
+}
+
+/// The side an entity fights on. Projectiles don't hurt their own side.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Faction {
+    /// The player's ship and station.
+    Player,
+    Enemy,
+}
+
+/// A gun firing projectiles as described by its weapon definition.
+#[derive(Component, Debug, Clone)]
+pub struct Weapon {
+    /// Id of the weapon definition.
+    pub definition: String,
+    /// Seconds until the next burst can be fired.
+    pub cooldown: f32,
+    /// Shots left in the current burst.
+    pub burst: u32,
+    /// Seconds until the next shot of the burst.
+    pub burst_delay: f32,
+    /// Direction the current burst is fired in.
+    pub aim: Vec2,
+}
+
+impl Weapon {
+    pub fn new(definition: impl Into<String>) -> Self {
+        Self {
+            definition: definition.into(),
+            cooldown: 0.0,
+            burst: 0,
+            burst_delay: 0.0,
+            aim: Vec2::X,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.cooldown <= 0.0 && self.burst == 0
+    }
+
+    /// Starts a burst in the direction, if the weapon is ready.
+    pub fn trigger(&mut self, definition: &WeaponDefinition, direction: Vec2) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+
+        self.cooldown = definition.cooldown;
+        self.burst = definition.burst.max(1);
+        self.burst_delay = 0.0;
+        self.aim = direction.normalize_or_zero();
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WeaponDefinition {
+    pub id: String,
+    pub name: String,
+    /// Speed of the projectiles in pixels per second, on top of the shooter's velocity.
+    pub speed: f32,
+    /// Seconds before projectiles that didn't hit anything expire.
+    pub lifetime: f32,
+    pub damage: f32,
+    #[serde(default)]
+    pub damage_type: DamageType,
+    /// Largest angle in radians a shot strays from the aim.
+    #[serde(default)]
+    pub spread: f32,
+    /// Shots fired per trigger pull.
+    #[serde(default = "default_burst")]
+    pub burst: u32,
+    /// Seconds between the shots of a burst.
+    #[serde(default)]
+    pub burst_interval: f32,
+    /// Seconds from the start of one burst to the next.
+    pub cooldown: f32,
+    /// Radius of the projectiles in pixels.
+    pub radius: f32,
+    /// Projectile color as linear rgb.
+    pub color: (f32, f32, f32),
+}
+
+fn default_burst() -> u32 {
+    1
+}
+
+/// The contents of an `*.weapons.ron` asset file.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct WeaponDefinitions {
+    pub weapons: Vec<WeaponDefinition>,
+}
+
+impl RonAsset for WeaponDefinitions {
+    const EXTENSIONS: &'static [&'static str] = &["weapons.ron"];
+}
+
+#[test]
+fn test_weapon_fires_bursts_after_cooldown() {
+    let definition: WeaponDefinition = ron::de::from_str(
+        r#"(id: "flak", name: "Flak", speed: 300.0, lifetime: 2.0, damage: 5.0, damage_type: Explosive,
+            burst: 3, burst_interval: 0.1, cooldown: 2.0, radius: 2.0, color: (1.0, 1.0, 1.0))"#,
+    )
+    .unwrap();
+    assert_eq!(definition.spread, 0.0);
+
+    let mut weapon = Weapon::new("flak");
+    assert!(weapon.trigger(&definition, Vec2::new(0.0, 5.0)));
+    assert_eq!(weapon.burst, 3);
+    assert_eq!(weapon.aim, Vec2::Y);
+
+    // Still cooling down, and busy with the burst.
+    assert!(!weapon.trigger(&definition, Vec2::X));
+    weapon.burst = 0;
+    assert!(!weapon.trigger(&definition, Vec2::X));
+
+    weapon.cooldown = 0.0;
+    assert!(weapon.trigger(&definition, Vec2::X));
 }
@@ -1,8 +1,8 @@
-use avian2d::prelude::LinearVelocity;
-use bevy::prelude::{Entity, Event};
+use bevy::prelude::{Entity, Event, Vec2};
 
+/// Pulls the trigger of the entity's weapon, firing a burst in the direction once it's ready.
 #[derive(Event)]
 pub struct FireProjectileEvent {
     pub entity: Entity,
-    pub projectile_trajectory: LinearVelocity,
+    pub direction: Vec2,
 }
@@ -1,20 +1,43 @@
 use bevy::prelude::*;
 
+use crate::ron_asset::{loader::RonAssetLoader, systems::rebuild_from_asset};
+
 use super::{
+    components::WeaponDefinitions,
     events::FireProjectileEvent,
-    systems::{handle_fire_projectile_events, handle_projectile_collision_event},
+    resources::{ProjectilePool, WeaponRegistry},
+    systems::{
+        clear_projectile_assets, fire_weapons, handle_fire_projectile_events,
+        handle_projectile_collision_event, load_weapon_definitions,
+    },
 };
 
 pub struct ProjectilePlugin;
 
 impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<FireProjectileEvent>().add_systems(
-            Update,
-            (
-                handle_projectile_collision_event,
-                handle_fire_projectile_events,
-            ),
-        );
+        app.init_asset::<WeaponDefinitions>()
+            .init_asset_loader::<RonAssetLoader<WeaponDefinitions>>()
+            .init_resource::<WeaponRegistry>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<FireProjectileEvent>()
+            .add_systems(PreStartup, load_weapon_definitions)
+            .add_systems(
+                PreUpdate,
+                (
+                    rebuild_from_asset::<WeaponDefinitions, WeaponRegistry>,
+                    clear_projectile_assets,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_projectile_collision_event,
+                    handle_fire_projectile_events,
+                    fire_weapons,
+                )
+                    .chain(),
+            );
     }
 }
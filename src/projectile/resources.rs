@@ -0,0 +1,49 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::ron_asset::systems::FromAsset;
+
+use super::components::{WeaponDefinition, WeaponDefinitions};
+
+pub const WEAPON_DEFINITIONS_PATH: &str = "data/core.weapons.ron";
+
+/// Most idle projectiles kept around for reuse, the rest are despawned.
+pub const MAX_IDLE_PROJECTILES: usize = 256;
+
+#[derive(Resource)]
+pub struct WeaponDefinitionsHandle(pub Handle<WeaponDefinitions>);
+
+/// Lookup table of every weapon, built from the weapon definitions asset.
+#[derive(Resource, Default, Debug)]
+pub struct WeaponRegistry {
+    definitions: HashMap<String, WeaponDefinition>,
+}
+
+impl FromAsset<WeaponDefinitions> for WeaponRegistry {
+    fn from_asset(definitions: &WeaponDefinitions) -> Self {
+        Self::from_definitions(definitions)
+    }
+}
+
+impl WeaponRegistry {
+    pub fn from_definitions(definitions: &WeaponDefinitions) -> Self {
+        Self {
+            definitions: definitions
+                .weapons
+                .iter()
+                .map(|definition| (definition.id.clone(), definition.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&WeaponDefinition> {
+        self.definitions.get(id)
+    }
+}
+
+/// Projectiles that hit something or expired, kept hidden to be fired again,
+/// along with the mesh and material each weapon's projectiles share.
+#[derive(Resource, Default)]
+pub struct ProjectilePool {
+    pub idle: Vec<Entity>,
+    pub assets: HashMap<String, (Handle<Mesh>, Handle<ColorMaterial>)>,
+}
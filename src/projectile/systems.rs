@@ -1,79 +1,295 @@
 use avian2d::prelude::*;
-use bevy::color::palettes::css::RED;
 use bevy::prelude::*;
+use rand::Rng;
 
-use crate::health::events::DamageEvent;
+use crate::{
+    health::events::DamageEvent,
+    world_seed::resources::{RngStream, SeededRng},
+};
 
-use super::components::Projectile;
+use super::components::{Faction, Projectile, Weapon, WeaponDefinition};
 use super::events::FireProjectileEvent;
+use super::resources::{
+    ProjectilePool, WeaponDefinitionsHandle, WeaponRegistry, MAX_IDLE_PROJECTILES,
+    WEAPON_DEFINITIONS_PATH,
+};
 
-pub const PROJECTILE_RADIUS: f64 = 0.160;
+/// Distance ahead of the shooter projectiles appear at.
+const MUZZLE_OFFSET: f32 = 20.0;
+
+pub fn load_weapon_definitions(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WeaponDefinitionsHandle(
+        asset_server.load(WEAPON_DEFINITIONS_PATH),
+    ));
+}
+
+/// Drops the meshes and materials shared by projectiles when the weapons are reloaded,
+/// as their colors and sizes may have changed.
+pub fn clear_projectile_assets(registry: Res<WeaponRegistry>, mut pool: ResMut<ProjectilePool>) {
+    if registry.is_changed() {
+        pool.assets.clear();
+    }
+}
 
 pub fn handle_fire_projectile_events(
-    mut commands: Commands,
-    global_transforms: Query<&GlobalTransform>,
     mut events: EventReader<FireProjectileEvent>,
+    mut weapon_query: Query<&mut Weapon>,
+    registry: Res<WeaponRegistry>,
+) {
+    for evt in events.read() {
+        let Ok(mut weapon) = weapon_query.get_mut(evt.entity) else {
+            continue;
+        };
+
+        if let Some(definition) = registry.get(&weapon.definition) {
+            weapon.trigger(definition, evt.direction);
+        }
+    }
+}
+
+/// Counts down weapon cooldowns and fires the shots of running bursts.
+#[allow(clippy::too_many_arguments)]
+pub fn fire_weapons(
+    mut commands: Commands,
+    mut weapon_query: Query<(
+        Entity,
+        &mut Weapon,
+        &GlobalTransform,
+        Option<&LinearVelocity>,
+        Option<&Faction>,
+    )>,
+    registry: Res<WeaponRegistry>,
+    mut pool: ResMut<ProjectilePool>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+    mut seeded_rng: ResMut<SeededRng>,
 ) {
-    for evt in events.read() {
-        const BULLET_SPEED: f32 = 6.0;
-
-        let projectile_shape = Circle::new((PROJECTILE_RADIUS * crate::PIXELS_PER_METER) as f32);
-
-        if let Ok(projectile_spawn_position) = global_transforms.get(evt.entity) {
-            // Move projectile slightly ahead of where it was fired from to avoid self collision
-            let projectile_spawn_position = projectile_spawn_position
-                .translation()
-                .truncate()
-                .as_dvec2()
-                + evt
-                    .projectile_trajectory
-                    .0
-                    .trunc()
-                    .normalize()
-                    .as_vec2()
-                    .as_dvec2()
-                    * 20.0;
-
-            commands.spawn((
-                Projectile {
-                    timer: Timer::from_seconds(5.0, TimerMode::Once),
-                },
-                Mesh2d(meshes.add(projectile_shape)),
-                MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::from(RED)))),
-                Transform::from_translation(projectile_spawn_position.extend(2.0).as_vec3()),
-                RigidBody::Dynamic,
-                evt.projectile_trajectory,
-                Collider::circle(projectile_shape.radius as f64),
-            ));
+    let rng = seeded_rng.stream(RngStream::WeaponSpread);
+
+    for (entity, mut weapon, g_transform, velocity, faction) in weapon_query.iter_mut() {
+        weapon.cooldown -= time.delta_secs();
+
+        if weapon.burst == 0 {
+            continue;
+        }
+
+        let Some(definition) = registry.get(&weapon.definition) else {
+            weapon.burst = 0;
+            continue;
+        };
+
+        weapon.burst_delay -= time.delta_secs();
+
+        while weapon.burst > 0 && weapon.burst_delay <= 0.0 {
+            weapon.burst -= 1;
+            weapon.burst_delay += definition.burst_interval;
+
+            let stray = if definition.spread > 0.0 {
+                rng.gen_range(-definition.spread..definition.spread)
+            } else {
+                0.0
+            };
+            let direction = Vec2::from_angle(stray).rotate(weapon.aim);
+
+            let shooter_velocity = velocity.map_or(Vec2::ZERO, |velocity| velocity.0.as_vec2());
+            let position = g_transform.translation().truncate() + weapon.aim * MUZZLE_OFFSET;
+
+            let projectile = Projectile {
+                timer: Timer::from_seconds(definition.lifetime, TimerMode::Once),
+                source: entity,
+                faction: faction.copied(),
+                damage: definition.damage,
+                damage_type: definition.damage_type,
+            };
+
+            spawn_projectile(
+                &mut commands,
+                &mut pool,
+                definition,
+                projectile,
+                position,
+                LinearVelocity((shooter_velocity + direction * definition.speed).as_dvec2()),
+                &mut meshes,
+                &mut materials,
+            );
         }
     }
 }
 
+/// Fires a projectile, reusing an idle one from the pool if there is any.
+#[allow(clippy::too_many_arguments)]
+fn spawn_projectile(
+    commands: &mut Commands,
+    pool: &mut ProjectilePool,
+    definition: &WeaponDefinition,
+    projectile: Projectile,
+    position: Vec2,
+    velocity: LinearVelocity,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    let (mesh, material) = pool
+        .assets
+        .entry(definition.id.clone())
+        .or_insert_with(|| {
+            let (red, green, blue) = definition.color;
+            (
+                meshes.add(Circle::new(definition.radius)),
+                materials.add(ColorMaterial::from_color(Color::linear_rgb(
+                    red, green, blue,
+                ))),
+            )
+        })
+        .clone();
+
+    let bundle = (
+        projectile,
+        Mesh2d(mesh),
+        MeshMaterial2d(material),
+        Transform::from_translation(position.extend(2.0)),
+        Visibility::Visible,
+        RigidBody::Dynamic,
+        // Reused projectiles still carry the physics position they were released at.
+        Position(position.as_dvec2()),
+        Rotation::default(),
+        velocity,
+        Collider::circle(definition.radius as f64),
+        // Projectiles are fired from inside station modules, so they only detect hits.
+        Sensor,
+    );
+
+    let reused = pool.idle.pop().and_then(|idle| commands.get_entity(idle));
+
+    match reused {
+        Some(mut entity_commands) => {
+            entity_commands.insert(bundle);
+        }
+        None => {
+            commands.spawn((Name::new("Projectile"), bundle));
+        }
+    }
+}
+
+/// Puts a projectile back into the pool, or despawns it when the pool is full.
+fn release_projectile(commands: &mut Commands, pool: &mut ProjectilePool, entity: Entity) {
+    if pool.idle.len() >= MAX_IDLE_PROJECTILES {
+        commands.entity(entity).despawn_recursive();
+        return;
+    }
+
+    commands
+        .entity(entity)
+        .remove::<(
+            Projectile,
+            RigidBody,
+            LinearVelocity,
+            Collider,
+            Sensor,
+            CollidingEntities,
+        )>()
+        .insert(Visibility::Hidden);
+    pool.idle.push(entity);
+}
+
+/// Damages whatever projectiles hit, leaving their own side and other projectiles alone,
+/// and returns projectiles that hit something or expired to the pool.
 pub fn handle_projectile_collision_event(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &CollidingEntities), With<Projectile>>,
+    mut projectile_query: Query<(Entity, &mut Projectile, Option<&CollidingEntities>)>,
+    faction_query: Query<&Faction>,
+    projectiles: Query<(), With<Projectile>>,
     mut damage_events: EventWriter<DamageEvent>,
+    mut pool: ResMut<ProjectilePool>,
+    time: Res<Time>,
 ) {
-    let mut projectiles_to_remove: Vec<Entity> = Vec::new();
-
-    for (projectile_ent, _projectile) in projectile_query.iter() {
-        if let Ok((_, colliding_entities)) = projectile_query.get(projectile_ent) {
-            for colliding_entity in colliding_entities.iter() {
-                damage_events.send(DamageEvent {
-                    entity: *colliding_entity,
-                    damage: 5.0,
-                });
-            }
-            if !colliding_entities.is_empty() {
-                projectiles_to_remove.push(projectile_ent);
-            }
+    for (projectile_ent, mut projectile, colliding_entities) in projectile_query.iter_mut() {
+        projectile.timer.tick(time.delta());
+
+        let hits: Vec<Entity> = colliding_entities
+            .into_iter()
+            .flat_map(|colliding_entities| colliding_entities.iter().copied())
+            .filter(|hit| *hit != projectile.source && !projectiles.contains(*hit))
+            .filter(|hit| {
+                projectile.faction.is_none()
+                    || faction_query.get(*hit).ok() != projectile.faction.as_ref()
+            })
+            .collect();
+
+        for hit in hits.iter() {
+            damage_events.send(DamageEvent {
+                entity: *hit,
+                damage: projectile.damage,
+                damage_type: projectile.damage_type,
+                source: Some(projectile.source),
+            });
         }
-    }
 
-    // Remove Projectiles that experienced a collision.
-    for projectile_to_remove in projectiles_to_remove.iter() {
-        commands.entity(*projectile_to_remove).despawn_recursive();
+        if !hits.is_empty() || projectile.timer.finished() {
+            release_projectile(&mut commands, &mut pool, projectile_ent);
+        }
     }
 }
+
+#[test]
+fn test_reused_projectile_is_fired_from_the_muzzle() {
+    use bevy::math::DVec2;
+
+    let definition: WeaponDefinition = ron::de::from_str(
+        r#"(id: "cannon", name: "Cannon", speed: 300.0, lifetime: 2.0, damage: 5.0, damage_type: Kinetic,
+            cooldown: 1.0, radius: 2.0, color: (1.0, 1.0, 1.0))"#,
+    )
+    .unwrap();
+
+    let mut world = World::new();
+    let mut pool = ProjectilePool::default();
+    let mut meshes = Assets::<Mesh>::default();
+    let mut materials = Assets::<ColorMaterial>::default();
+
+    let mut fire = |world: &mut World, pool: &mut ProjectilePool, position: Vec2| {
+        let projectile = Projectile {
+            timer: Timer::from_seconds(definition.lifetime, TimerMode::Once),
+            source: Entity::PLACEHOLDER,
+            faction: None,
+            damage: definition.damage,
+            damage_type: definition.damage_type,
+        };
+        spawn_projectile(
+            &mut world.commands(),
+            pool,
+            &definition,
+            projectile,
+            position,
+            LinearVelocity(DVec2::X),
+            &mut meshes,
+            &mut materials,
+        );
+        world.flush();
+    };
+
+    fire(&mut world, &mut pool, Vec2::new(100.0, 0.0));
+    let entity = world
+        .query_filtered::<Entity, With<Projectile>>()
+        .single(&world);
+    assert_eq!(
+        world.get::<Position>(entity).unwrap().0,
+        DVec2::new(100.0, 0.0)
+    );
+
+    // The projectile flies off before it hits something.
+    world
+        .entity_mut(entity)
+        .insert(Position(DVec2::new(500.0, 500.0)));
+    release_projectile(&mut world.commands(), &mut pool, entity);
+    world.flush();
+    assert!(!world.entity(entity).contains::<Projectile>());
+    assert_eq!(pool.idle, vec![entity]);
+
+    fire(&mut world, &mut pool, Vec2::new(-50.0, 20.0));
+    assert!(pool.idle.is_empty());
+    assert!(world.entity(entity).contains::<Projectile>());
+    assert_eq!(
+        world.get::<Position>(entity).unwrap().0,
+        DVec2::new(-50.0, 20.0)
+    );
+}
@@ -38,6 +38,7 @@ pub fn handle_thrust_events(
         ),
         With<PlayerShipTrailParticles>,
     >,
+    player_query: Query<(), With<Player>>,
 ) {
 
     let evt = trigger.event();
@@ -55,6 +56,11 @@ pub fn handle_thrust_events(
             drain: energy_spent,
         });
 
+        // The engine trail belongs to the player's ship.
+        if !player_query.contains(evt.entity) {
+            return;
+        }
+
         // Note: On first frame where the effect spawns, EffectSpawner is spawned during
         // PostUpdate, so will not be available yet. Ignore for a frame if so.
         let Ok((mut properties, mut initializers, mut effect_transform)) =
@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::{
+    inventory::components::InventoryItem, item_producer::components::ProducerKind,
+    items::components::ItemId, ron_asset::loader::RonAsset,
+};
+
+/// An item a recipe sometimes produces alongside its outputs.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Byproduct {
+    pub item: InventoryItem,
+    /// Chance from 0.0 to 1.0 that the byproduct comes out of a finished run.
+    pub chance: f32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Recipe {
+    pub id: String,
+    pub items_required: Vec<InventoryItem>,
+    pub outputs: Vec<InventoryItem>,
+    pub byproducts: Vec<Byproduct>,
+    pub time_required: f32,
+}
+
+impl Recipe {
+    /// Returns everything a single run of the recipe produces,
+    /// with each byproduct rolled against its chance.
+    pub fn roll_outputs(&self, rng: &mut impl Rng) -> Vec<InventoryItem> {
+        let mut produced = self.outputs.clone();
+
+        produced.extend(
+            self.byproducts
+                .iter()
+                .filter(|byproduct| rng.gen::<f32>() < byproduct.chance)
+                .map(|byproduct| byproduct.item.clone()),
+        );
+
+        produced
+    }
+}
+
+/// An item and how much of it, in kilograms or units depending on how the item stacks.
+pub type RecipeItem = (ItemId, f32);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ByproductDefinition {
+    pub item: RecipeItem,
+    pub chance: f32,
+}
+
+/// A recipe as written in a recipe book file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecipeDefinition {
+    pub id: String,
+    pub producer: ProducerKind,
+    pub inputs: Vec<RecipeItem>,
+    pub outputs: Vec<RecipeItem>,
+    #[serde(default)]
+    pub byproducts: Vec<ByproductDefinition>,
+    /// Processing time in seconds.
+    pub time: f32,
+    /// Line of the file the recipe is defined on, filled in by the loader.
+    #[serde(skip)]
+    pub line: Option<usize>,
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct RecipeBook {
+    pub recipes: Vec<RecipeDefinition>,
+    /// Path of the file the recipes were loaded from, filled in by the loader.
+    #[serde(skip)]
+    pub path: String,
+}
+
+impl RonAsset for RecipeBook {
+    const EXTENSIONS: &'static [&'static str] = &["recipes.ron"];
+
+    /// Remembers where each recipe came from so validation errors can point at it.
+    fn loaded(&mut self, source: &str, path: &Path) {
+        self.path = path.display().to_string();
+        for recipe in self.recipes.iter_mut() {
+            recipe.line = line_of(source, &recipe.id);
+        }
+    }
+}
+
+/// Returns the line the recipe with the given id is defined on,
+/// or None if the id is defined on more than one line.
+fn line_of(source: &str, id: &str) -> Option<usize> {
+    let quoted = format!("\"{}\"", id);
+
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains("id:") && line.contains(&quoted))
+        .map(|(index, _)| index + 1);
+
+    match (lines.next(), lines.next()) {
+        (Some(line), None) => Some(line),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_duplicate_ids_have_no_line() {
+    let source = r#"(recipes: [
+        (id: "smelt", producer: Refinery, inputs: [], outputs: [], time: 1.0),
+        (id: "press", producer: Factory, inputs: [], outputs: [], time: 1.0),
+        (id: "smelt", producer: Refinery, inputs: [], outputs: [], time: 2.0),
+    ])"#;
+
+    assert_eq!(line_of(source, "press"), Some(3));
+    assert_eq!(line_of(source, "smelt"), None);
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::{
+    items::{components::ItemDefinitions, resources::ItemRegistry},
+    ron_asset::{loader::RonAssetLoader, systems::rebuild_from_asset},
+};
+
+use super::{
+    components::RecipeBook,
+    resources::Recipes,
+    systems::{load_recipe_book, update_recipes},
+};
+
+pub struct RecipesPlugin;
+
+impl Plugin for RecipesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<RecipeBook>()
+            .init_asset_loader::<RonAssetLoader<RecipeBook>>()
+            .init_resource::<Recipes>()
+            .add_systems(PreStartup, load_recipe_book)
+            .add_systems(
+                PreUpdate,
+                update_recipes.after(rebuild_from_asset::<ItemDefinitions, ItemRegistry>),
+            );
+    }
+}
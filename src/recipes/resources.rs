@@ -0,0 +1,62 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    inventory::components::InventoryItem, item_producer::components::ProducerKind,
+    items::resources::ItemRegistry,
+};
+
+use super::components::{Byproduct, Recipe, RecipeBook, RecipeItem};
+
+pub const RECIPE_BOOK_PATH: &str = "data/core.recipes.ron";
+
+#[derive(Resource)]
+pub struct RecipeBookHandle(pub Handle<RecipeBook>);
+
+/// Every recipe in the game by the kind of producer that makes it, built from the recipe book.
+#[derive(Resource, Default, Debug)]
+pub struct Recipes {
+    by_producer: HashMap<ProducerKind, Vec<Recipe>>,
+}
+
+impl Recipes {
+    pub fn from_book(book: &RecipeBook, registry: &ItemRegistry) -> Self {
+        let mut by_producer: HashMap<ProducerKind, Vec<Recipe>> = HashMap::new();
+
+        let to_item =
+            |(id, amount): &RecipeItem| InventoryItem(id.clone(), registry.amount_of(id, *amount));
+
+        for definition in book.recipes.iter() {
+            by_producer
+                .entry(definition.producer)
+                .or_default()
+                .push(Recipe {
+                    id: definition.id.clone(),
+                    items_required: definition.inputs.iter().map(to_item).collect(),
+                    outputs: definition.outputs.iter().map(to_item).collect(),
+                    byproducts: definition
+                        .byproducts
+                        .iter()
+                        .map(|byproduct| Byproduct {
+                            item: to_item(&byproduct.item),
+                            chance: byproduct.chance,
+                        })
+                        .collect(),
+                    time_required: definition.time,
+                });
+        }
+
+        Self { by_producer }
+    }
+
+    /// Returns the recipes made by the given kind of producer.
+    pub fn for_producer(&self, kind: ProducerKind) -> Vec<Recipe> {
+        self.by_producer.get(&kind).cloned().unwrap_or_default()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Recipe> {
+        self.by_producer
+            .values()
+            .flatten()
+            .find(|recipe| recipe.id == id)
+    }
+}
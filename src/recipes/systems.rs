@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::{items::resources::ItemRegistry, ron_asset::systems::asset_reloaded};
+
+use super::{
+    components::RecipeBook,
+    resources::{RecipeBookHandle, Recipes, RECIPE_BOOK_PATH},
+};
+
+pub fn load_recipe_book(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(RecipeBookHandle(asset_server.load(RECIPE_BOOK_PATH)));
+}
+
+/// Rebuilds the Recipes whenever the recipe book or the item registry changes.
+/// A recipe book with errors is reported and ignored, keeping the last valid recipes.
+pub fn update_recipes(
+    mut asset_events: EventReader<AssetEvent<RecipeBook>>,
+    recipe_books: Res<Assets<RecipeBook>>,
+    recipe_book_handle: Res<RecipeBookHandle>,
+    item_registry: Res<ItemRegistry>,
+    mut recipes: ResMut<Recipes>,
+) {
+    let book_changed = asset_reloaded(&mut asset_events);
+
+    if !(book_changed || item_registry.is_changed()) || item_registry.is_empty() {
+        return;
+    }
+
+    let Some(book) = recipe_books.get(&recipe_book_handle.0) else {
+        return;
+    };
+
+    let errors = book.validate(&item_registry);
+    if !errors.is_empty() {
+        for error in errors.iter() {
+            error!("{}", error);
+        }
+        return;
+    }
+
+    *recipes = Recipes::from_book(book, &item_registry);
+    info!("Loaded {} recipes.", book.recipes.len());
+}
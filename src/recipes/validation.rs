@@ -0,0 +1,228 @@
+use bevy::utils::{HashMap, HashSet};
+use thiserror::Error;
+
+use crate::items::{
+    components::{ItemId, Stacking},
+    resources::ItemRegistry,
+};
+
+use super::components::{RecipeBook, RecipeDefinition};
+
+/// Problems found in a recipe book, each pointing at the file and line of the recipe.
+#[derive(Debug, Error, PartialEq)]
+pub enum RecipeError {
+    #[error("{location}: recipe \"{recipe}\" is defined more than once")]
+    DuplicateId { location: String, recipe: String },
+    #[error("{location}: recipe \"{recipe}\" uses unknown item \"{item}\"")]
+    UnknownItem {
+        location: String,
+        recipe: String,
+        item: String,
+    },
+    #[error("{location}: recipe \"{recipe}\" needs a whole number of \"{item}\", not {amount}")]
+    FractionalQuantity {
+        location: String,
+        recipe: String,
+        item: String,
+        amount: f32,
+    },
+    #[error("{location}: recipe \"{recipe}\" has no outputs")]
+    NoOutputs { location: String, recipe: String },
+    #[error("{location}: recipe \"{recipe}\" takes {time} seconds, it must take longer than zero")]
+    InvalidTime {
+        location: String,
+        recipe: String,
+        time: f32,
+    },
+    #[error("{location}: recipe \"{recipe}\" is part of a cycle: {}", cycle.join(" -> "))]
+    Cycle {
+        location: String,
+        recipe: String,
+        cycle: Vec<String>,
+    },
+}
+
+impl RecipeBook {
+    /// Returns "path:line" for the recipe, or just the path if its line is unknown.
+    pub fn location(&self, recipe: &RecipeDefinition) -> String {
+        match recipe.line {
+            Some(line) => format!("{}:{}", self.path, line),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Checks the recipe book against the items in the registry.
+    pub fn validate(&self, registry: &ItemRegistry) -> Vec<RecipeError> {
+        let mut errors = vec![];
+        let mut seen = HashSet::new();
+
+        for recipe in self.recipes.iter() {
+            let location = self.location(recipe);
+
+            // The loader can't tell which of the definitions is on which line,
+            // so duplicates only point at the file.
+            if !seen.insert(&recipe.id) {
+                errors.push(RecipeError::DuplicateId {
+                    location: self.path.clone(),
+                    recipe: recipe.id.clone(),
+                });
+            }
+
+            if recipe.outputs.is_empty() {
+                errors.push(RecipeError::NoOutputs {
+                    location: location.clone(),
+                    recipe: recipe.id.clone(),
+                });
+            }
+
+            if recipe.time <= 0.0 {
+                errors.push(RecipeError::InvalidTime {
+                    location: location.clone(),
+                    recipe: recipe.id.clone(),
+                    time: recipe.time,
+                });
+            }
+
+            let items = recipe
+                .inputs
+                .iter()
+                .chain(recipe.outputs.iter())
+                .chain(recipe.byproducts.iter().map(|byproduct| &byproduct.item));
+
+            for (item, amount) in items {
+                let Some(definition) = registry.get(item) else {
+                    errors.push(RecipeError::UnknownItem {
+                        location: location.clone(),
+                        recipe: recipe.id.clone(),
+                        item: item.to_string(),
+                    });
+                    continue;
+                };
+
+                if definition.stacking == Stacking::Quantity && amount.fract() != 0.0 {
+                    errors.push(RecipeError::FractionalQuantity {
+                        location: location.clone(),
+                        recipe: recipe.id.clone(),
+                        item: item.to_string(),
+                        amount: *amount,
+                    });
+                }
+            }
+        }
+
+        if let Some(cycle) = self.find_cycle() {
+            errors.push(RecipeError::Cycle {
+                location: self.location(cycle[0]),
+                recipe: cycle[0].id.clone(),
+                cycle: cycle.iter().map(|recipe| recipe.id.clone()).collect(),
+            });
+        }
+
+        errors
+    }
+
+    /// Returns the recipes of a loop in which an item is, indirectly, made from itself.
+    fn find_cycle(&self) -> Option<Vec<&RecipeDefinition>> {
+        let mut edges: Edges = HashMap::new();
+
+        for recipe in self.recipes.iter() {
+            for (input, _) in recipe.inputs.iter() {
+                for (output, _) in recipe.outputs.iter() {
+                    edges.entry(input).or_default().push((output, recipe));
+                }
+            }
+        }
+
+        let mut finished = HashSet::new();
+        let mut path = vec![];
+
+        edges
+            .keys()
+            .find_map(|start| visit(*start, None, &edges, &mut path, &mut finished))
+    }
+}
+
+/// For each item, the items that recipes make out of it.
+type Edges<'a> = HashMap<&'a ItemId, Vec<(&'a ItemId, &'a RecipeDefinition)>>;
+
+/// Depth first search through the items produced from `item`,
+/// returning the recipes along the path once it leads back to an item already on it.
+fn visit<'a>(
+    item: &'a ItemId,
+    via: Option<&'a RecipeDefinition>,
+    edges: &Edges<'a>,
+    path: &mut Vec<(&'a ItemId, Option<&'a RecipeDefinition>)>,
+    finished: &mut HashSet<&'a ItemId>,
+) -> Option<Vec<&'a RecipeDefinition>> {
+    if let Some(index) = path.iter().position(|(on_path, _)| *on_path == item) {
+        let mut cycle: Vec<_> = path[index + 1..]
+            .iter()
+            .filter_map(|(_, recipe)| *recipe)
+            .collect();
+        cycle.extend(via);
+        return Some(cycle);
+    }
+
+    if finished.contains(item) {
+        return None;
+    }
+
+    path.push((item, via));
+    for (next, recipe) in edges.get(item).into_iter().flatten() {
+        if let Some(cycle) = visit(next, Some(*recipe), edges, path, finished) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+
+    finished.insert(item);
+    None
+}
+
+#[cfg(test)]
+fn test_book(recipes: &str) -> RecipeBook {
+    let mut book: RecipeBook = ron::de::from_str(recipes).unwrap();
+    book.path = "test.recipes.ron".to_string();
+    book
+}
+
+#[test]
+fn test_unknown_items_and_zero_times_are_reported() {
+    let book = test_book(
+        r#"(recipes: [
+            (id: "iron_ingot", producer: Refinery, inputs: [("unobtanium", 20.0)], outputs: [("iron_ingot", 0.5)], time: 0.0),
+        ])"#,
+    );
+
+    let errors = book.validate(&ItemRegistry::iron_for_tests());
+
+    assert!(errors.contains(&RecipeError::UnknownItem {
+        location: "test.recipes.ron".to_string(),
+        recipe: "iron_ingot".to_string(),
+        item: "unobtanium".to_string(),
+    }));
+    assert!(errors
+        .iter()
+        .any(|error| matches!(error, RecipeError::FractionalQuantity { .. })));
+    assert!(errors
+        .iter()
+        .any(|error| matches!(error, RecipeError::InvalidTime { .. })));
+}
+
+#[test]
+fn test_cycles_are_reported() {
+    let book = test_book(
+        r#"(recipes: [
+            (id: "smelt", producer: Refinery, inputs: [("iron_ore", 20.0)], outputs: [("iron_ingot", 1.0)], time: 2.0),
+            (id: "unsmelt", producer: Factory, inputs: [("iron_ingot", 1.0)], outputs: [("iron_ore", 20.0)], time: 2.0),
+        ])"#,
+    );
+
+    let errors = book.validate(&ItemRegistry::iron_for_tests());
+
+    assert_eq!(errors.len(), 1);
+    let RecipeError::Cycle { cycle, .. } = &errors[0] else {
+        panic!("expected a cycle, got {:?}", errors[0]);
+    };
+    assert_eq!(cycle.len(), 2);
+}
@@ -0,0 +1,137 @@
+use bevy::{prelude::*, reflect::TypePath, utils::HashSet};
+use serde::Deserialize;
+
+use crate::{
+    inventory::components::InventoryItem, items::resources::ItemRegistry,
+    recipes::components::RecipeItem, ron_asset::loader::RonAsset,
+    space_station::modules::components::SpaceStationModuleType, upgrades::components::UpgradeType,
+};
+
+/// Something a tech node makes available once it has been researched.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub enum Unlock {
+    /// A recipe from the recipe book, by id.
+    Recipe(String),
+    /// A module that can be placed in build mode.
+    Module(SpaceStationModuleType),
+    /// A single upgrade tier, ie. `Health(Level2)`.
+    Upgrade(UpgradeType),
+}
+
+/// A node of the tech tree as written in the tech tree file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TechNode {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Ids of the nodes that have to be researched first.
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    /// Items paid from the station inventory when the research starts.
+    #[serde(default)]
+    pub cost: Vec<RecipeItem>,
+    /// Research time in seconds.
+    pub time: f32,
+    pub unlocks: Vec<Unlock>,
+}
+
+impl TechNode {
+    /// Returns the cost of the node as inventory items.
+    pub fn items_required(&self, registry: &ItemRegistry) -> Vec<InventoryItem> {
+        self.cost
+            .iter()
+            .map(|(id, amount)| InventoryItem(id.clone(), registry.amount_of(id, *amount)))
+            .collect()
+    }
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct TechTree {
+    pub nodes: Vec<TechNode>,
+}
+
+impl RonAsset for TechTree {
+    const EXTENSIONS: &'static [&'static str] = &["tech.ron"];
+}
+
+impl TechTree {
+    pub fn get(&self, id: &str) -> Option<&TechNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns every unlock that is still gated behind a node that hasn't been completed.
+    /// Anything the tree doesn't mention is available from the start.
+    pub fn locked(&self, completed: &HashSet<String>) -> HashSet<Unlock> {
+        let (researched, pending): (Vec<_>, Vec<_>) = self
+            .nodes
+            .iter()
+            .partition(|node| completed.contains(&node.id));
+
+        let unlocked: HashSet<&Unlock> = researched
+            .iter()
+            .flat_map(|node| node.unlocks.iter())
+            .collect();
+
+        pending
+            .iter()
+            .flat_map(|node| node.unlocks.iter())
+            .filter(|unlock| !unlocked.contains(unlock))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the ids of prerequisites that don't name a node in the tree.
+    pub fn unknown_prerequisites(&self) -> Vec<(&str, &str)> {
+        self.nodes
+            .iter()
+            .flat_map(|node| {
+                node.prerequisites
+                    .iter()
+                    .filter(|prerequisite| self.get(prerequisite).is_none())
+                    .map(|prerequisite| (node.id.as_str(), prerequisite.as_str()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+fn test_tree() -> TechTree {
+    ron::de::from_str(
+        r#"(nodes: [
+            (id: "fabrication", name: "Fabrication", time: 10.0, unlocks: [Module(Factory), Recipe("cog")]),
+            (id: "defense", name: "Defense", prerequisites: ["fabrication"], time: 10.0, unlocks: [Module(Turret), Recipe("cog")]),
+        ])"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_unlocks_stay_locked_until_researched() {
+    let tree = test_tree();
+
+    let locked = tree.locked(&HashSet::new());
+    assert!(locked.contains(&Unlock::Module(SpaceStationModuleType::Factory)));
+    assert!(locked.contains(&Unlock::Module(SpaceStationModuleType::Turret)));
+    assert!(locked.contains(&Unlock::Recipe("cog".to_string())));
+    assert!(!locked.contains(&Unlock::Module(SpaceStationModuleType::Storage)));
+
+    // An unlock shared by two nodes is available once either of them is researched.
+    let locked = tree.locked(&HashSet::from_iter(["fabrication".to_string()]));
+    assert!(!locked.contains(&Unlock::Module(SpaceStationModuleType::Factory)));
+    assert!(!locked.contains(&Unlock::Recipe("cog".to_string())));
+    assert!(locked.contains(&Unlock::Module(SpaceStationModuleType::Turret)));
+}
+
+#[test]
+fn test_everything_is_locked_until_the_tree_loads() {
+    use super::resources::ResearchUnlocks;
+
+    let mut unlocks = ResearchUnlocks::default();
+    assert!(!unlocks.is_unlocked(&Unlock::Module(SpaceStationModuleType::Storage)));
+    assert!(!unlocks.is_recipe_unlocked("cog"));
+
+    unlocks.locked = Some(test_tree().locked(&HashSet::new()));
+    assert!(unlocks.is_unlocked(&Unlock::Module(SpaceStationModuleType::Storage)));
+    assert!(!unlocks.is_recipe_unlocked("cog"));
+}
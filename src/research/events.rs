@@ -0,0 +1,14 @@
+use bevy::prelude::Event;
+
+/// Requests from the player to change what the station is researching.
+#[derive(Event, Debug, Clone)]
+pub enum ResearchEvent {
+    Start { tech: String },
+    Cancel,
+}
+
+/// Sent when a tech node finishes researching.
+#[derive(Event, Debug, Clone)]
+pub struct ResearchCompletedEvent {
+    pub tech: String,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::ron_asset::loader::RonAssetLoader;
+
+use super::{
+    components::TechTree,
+    events::{ResearchCompletedEvent, ResearchEvent},
+    resources::{ResearchState, ResearchUnlocks},
+    systems::{load_tech_tree, on_research_event, update_research, update_research_unlocks},
+};
+
+pub struct ResearchPlugin;
+
+impl Plugin for ResearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TechTree>()
+            .init_asset_loader::<RonAssetLoader<TechTree>>()
+            .init_resource::<ResearchState>()
+            .init_resource::<ResearchUnlocks>()
+            .add_event::<ResearchEvent>()
+            .add_event::<ResearchCompletedEvent>()
+            .add_systems(PreStartup, load_tech_tree)
+            .add_systems(
+                Update,
+                (on_research_event, update_research, update_research_unlocks).chain(),
+            );
+    }
+}
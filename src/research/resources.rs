@@ -0,0 +1,76 @@
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::inventory::components::Inventory;
+
+use super::components::{TechNode, TechTree, Unlock};
+
+pub const TECH_TREE_PATH: &str = "data/core.tech.ron";
+
+#[derive(Resource)]
+pub struct TechTreeHandle(pub Handle<TechTree>);
+
+/// The node being researched, holding the items paid for it until it finishes or is cancelled.
+#[derive(Debug, Clone)]
+pub struct ActiveResearch {
+    pub tech: String,
+    pub timer: Timer,
+    pub paid: Inventory,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct ResearchState {
+    pub completed: HashSet<String>,
+    pub active: Option<ActiveResearch>,
+}
+
+impl ResearchState {
+    pub fn is_completed(&self, tech: &str) -> bool {
+        self.completed.contains(tech)
+    }
+
+    pub fn is_researching(&self, tech: &str) -> bool {
+        self.active
+            .as_ref()
+            .is_some_and(|active| active.tech == tech)
+    }
+
+    /// A node can be researched once all of its prerequisites are completed.
+    pub fn is_available(&self, node: &TechNode) -> bool {
+        !self.is_completed(&node.id)
+            && node
+                .prerequisites
+                .iter()
+                .all(|prerequisite| self.is_completed(prerequisite))
+    }
+
+    /// Returns how far along the active research is, from 0.0 to 1.0.
+    pub fn progress(&self) -> Option<f32> {
+        self.active.as_ref().map(|active| active.timer.fraction())
+    }
+
+    pub fn remaining_time(&self) -> Option<f32> {
+        self.active
+            .as_ref()
+            .map(|active| active.timer.remaining_secs())
+    }
+}
+
+/// Recipes, modules and upgrade tiers that still need to be researched.
+/// Kept apart from the ResearchState so it only changes when research completes.
+#[derive(Resource, Default, Debug)]
+pub struct ResearchUnlocks {
+    /// None until the tech tree has loaded, and everything counts as locked until then.
+    pub locked: Option<HashSet<Unlock>>,
+}
+
+impl ResearchUnlocks {
+    pub fn is_unlocked(&self, unlock: &Unlock) -> bool {
+        self.locked
+            .as_ref()
+            .is_some_and(|locked| !locked.contains(unlock))
+    }
+
+    pub fn is_recipe_unlocked(&self, recipe: &str) -> bool {
+        self.is_unlocked(&Unlock::Recipe(recipe.to_string()))
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use crate::{
+    inventory::components::Inventory, items::resources::ItemRegistry,
+    ron_asset::systems::asset_reloaded, space_station::components::SpaceStation,
+};
+
+use super::{
+    components::TechTree,
+    events::{ResearchCompletedEvent, ResearchEvent},
+    resources::{ActiveResearch, ResearchState, ResearchUnlocks, TechTreeHandle, TECH_TREE_PATH},
+};
+
+pub fn load_tech_tree(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(TechTreeHandle(asset_server.load(TECH_TREE_PATH)));
+}
+
+/// Starts or cancels research, paying the cost from the station inventory
+/// and refunding it when the research is cancelled.
+pub fn on_research_event(
+    mut reader: EventReader<ResearchEvent>,
+    mut research: ResMut<ResearchState>,
+    tech_trees: Res<Assets<TechTree>>,
+    tech_tree_handle: Res<TechTreeHandle>,
+    mut station_query: Query<&mut Inventory, With<SpaceStation>>,
+    item_registry: Res<ItemRegistry>,
+) {
+    let Ok(mut inventory) = station_query.get_single_mut() else {
+        return;
+    };
+
+    for event in reader.read() {
+        match event {
+            ResearchEvent::Start { tech } => {
+                let Some(node) = tech_trees
+                    .get(&tech_tree_handle.0)
+                    .and_then(|tree| tree.get(tech))
+                else {
+                    warn!("Unknown tech \"{}\".", tech);
+                    continue;
+                };
+
+                if research.active.is_some() || !research.is_available(node) {
+                    warn!("Can't start researching \"{}\" right now.", tech);
+                    continue;
+                }
+
+                let mut paid = Inventory::unbounded();
+                if let Err(missing) = inventory.transfer_items(
+                    &mut paid,
+                    &node.items_required(&item_registry),
+                    &item_registry,
+                ) {
+                    warn!("Missing {:?} to research \"{}\".", missing, tech);
+                    continue;
+                }
+
+                research.active = Some(ActiveResearch {
+                    tech: tech.clone(),
+                    timer: Timer::from_seconds(node.time, TimerMode::Once),
+                    paid,
+                });
+            }
+            ResearchEvent::Cancel => {
+                if let Some(mut active) = research.active.take() {
+                    active.paid.transfer_all(&mut inventory, &item_registry);
+                }
+            }
+        }
+    }
+}
+
+pub fn update_research(
+    time: Res<Time>,
+    mut research: ResMut<ResearchState>,
+    mut completed_events: EventWriter<ResearchCompletedEvent>,
+) {
+    let Some(active) = research.active.as_mut() else {
+        return;
+    };
+
+    if !active.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let tech = active.tech.clone();
+    research.active = None;
+    research.completed.insert(tech.clone());
+
+    info!("Finished researching \"{}\".", tech);
+    completed_events.send(ResearchCompletedEvent { tech });
+}
+
+/// Recomputes what is still locked when the tech tree is loaded or research completes.
+pub fn update_research_unlocks(
+    mut asset_events: EventReader<AssetEvent<TechTree>>,
+    mut completed_events: EventReader<ResearchCompletedEvent>,
+    tech_trees: Res<Assets<TechTree>>,
+    tech_tree_handle: Res<TechTreeHandle>,
+    research: Res<ResearchState>,
+    mut unlocks: ResMut<ResearchUnlocks>,
+) {
+    let tree_changed = asset_reloaded(&mut asset_events);
+    let research_completed = completed_events.read().count() > 0;
+
+    if !(tree_changed || research_completed) {
+        return;
+    }
+
+    let Some(tree) = tech_trees.get(&tech_tree_handle.0) else {
+        return;
+    };
+
+    if tree_changed {
+        for (node, prerequisite) in tree.unknown_prerequisites() {
+            error!(
+                "Tech \"{}\" requires unknown tech \"{}\" and can never be researched.",
+                node, prerequisite
+            );
+        }
+    }
+
+    unlocks.locked = Some(tree.locked(&research.completed));
+}
@@ -0,0 +1,58 @@
+use std::{marker::PhantomData, path::Path};
+
+use bevy::asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+/// An asset read from a RON file.
+pub trait RonAsset: Asset + DeserializeOwned {
+    /// File extensions the asset is loaded from, ie. "items.ron".
+    const EXTENSIONS: &'static [&'static str];
+
+    /// Called after the asset is parsed, to fill in anything that depends on the file itself.
+    fn loaded(&mut self, _source: &str, _path: &Path) {}
+}
+
+/// Loads any RonAsset from its files.
+pub struct RonAssetLoader<T> {
+    _asset: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for RonAssetLoader<T> {
+    fn default() -> Self {
+        Self {
+            _asset: PhantomData,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RonAssetLoaderError {
+    #[error("could not read asset: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse asset: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl<T: RonAsset> AssetLoader for RonAssetLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = RonAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut asset = ron::de::from_bytes::<T>(&bytes)?;
+        asset.loaded(&String::from_utf8_lossy(&bytes), load_context.path());
+        Ok(asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        T::EXTENSIONS
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::{prelude::*, reflect::TypePath};
+
+/// A resource built from the contents of an asset, ie. a registry from its definitions file.
+pub trait FromAsset<A: Asset> {
+    fn from_asset(asset: &A) -> Self;
+}
+
+/// Returns true if any of the read asset events is a load finishing or a change on disk.
+pub fn asset_reloaded<A: Asset>(asset_events: &mut EventReader<AssetEvent<A>>) -> bool {
+    asset_events.read().fold(false, |reloaded, event| {
+        reloaded
+            || matches!(
+                event,
+                AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Modified { .. }
+            )
+    })
+}
+
+/// Rebuilds the resource whenever the asset it is built from finishes loading or changes on disk.
+pub fn rebuild_from_asset<A: Asset, R: Resource + FromAsset<A>>(
+    mut asset_events: EventReader<AssetEvent<A>>,
+    assets: Res<Assets<A>>,
+    mut resource: ResMut<R>,
+) {
+    for event in asset_events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                if let Some(asset) = assets.get(*id) {
+                    *resource = R::from_asset(asset);
+                    info!("Loaded {}.", A::short_type_path());
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::PIXELS_PER_METER;
+
+const SCAN_COST: f32 = 50.0;
+const SCAN_RANGE: f32 = 300.0 * PIXELS_PER_METER as f32;
+const SCAN_SPEED: f32 = 150.0 * PIXELS_PER_METER as f32;
+const SCAN_COOLDOWN: f32 = 3.0;
+
+/// A ship subsystem sending out scan pulses that reveal what asteroids are made of.
+#[derive(Component, Debug, Clone)]
+pub struct Scanner {
+    /// Battery charge a scan pulse costs.
+    pub cost: f32,
+    /// Radius a scan pulse reaches, in pixels.
+    pub range: f32,
+    /// Speed a scan pulse expands with, in pixels per second.
+    pub speed: f32,
+    pub cooldown: Timer,
+}
+
+impl Scanner {
+    pub fn is_ready(&self) -> bool {
+        self.cooldown.finished()
+    }
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(SCAN_COOLDOWN, TimerMode::Once);
+        // Ready to scan straight away.
+        cooldown.tick(cooldown.duration());
+
+        Self {
+            cost: SCAN_COST,
+            range: SCAN_RANGE,
+            speed: SCAN_SPEED,
+            cooldown,
+        }
+    }
+}
+
+/// An expanding ring, scanning the asteroids it passes over.
+#[derive(Component, Debug, Clone)]
+pub struct ScanPulse {
+    pub radius: f32,
+    pub range: f32,
+    pub speed: f32,
+}
+
+/// What a scan revealed about an asteroid, on top of its composition.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Scanned {
+    /// Trade value in credits of the ore in the asteroid.
+    pub estimated_value: f32,
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::{Entity, Event};
+
+/// Asks the scanner of an entity to send out a scan pulse.
+#[derive(Event)]
+pub struct ScanEvent {
+    pub entity: Entity,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use super::{
+    events::ScanEvent,
+    systems::{
+        draw_scan_pulses, draw_scanned_outlines, expand_scan_pulses, on_scan_event, scanner_input,
+        tick_scanner_cooldowns,
+    },
+};
+
+pub struct ScannerPlugin;
+
+impl Plugin for ScannerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ScanEvent>()
+            .add_systems(
+                Update,
+                (
+                    tick_scanner_cooldowns,
+                    scanner_input.after(tick_scanner_cooldowns),
+                    expand_scan_pulses,
+                    draw_scan_pulses,
+                    draw_scanned_outlines,
+                ),
+            )
+            .add_observer(on_scan_event);
+    }
+}
@@ -0,0 +1,141 @@
+use avian2d::prelude::Mass;
+use bevy::{color::palettes::css::AQUA, prelude::*};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    asteroid::components::Asteroid,
+    battery::{components::Battery, events::DrainBatteryEvent},
+    inventory::components::InventoryItem,
+    items::{resources::ItemRegistry, Amount},
+    player::components::Player,
+};
+
+use super::{
+    components::{ScanPulse, Scanned, Scanner},
+    events::ScanEvent,
+};
+
+pub fn scanner_input(
+    mut commands: Commands,
+    kb: Res<ButtonInput<KeyCode>>,
+    player_query: Query<Entity, (With<Player>, With<Scanner>)>,
+) {
+    if !kb.just_pressed(KeyCode::KeyQ) {
+        return;
+    }
+
+    if let Ok(entity) = player_query.get_single() {
+        commands.trigger(ScanEvent { entity });
+    }
+}
+
+/// Sends out a scan pulse from the scanning ship, if the scanner is ready
+/// and the battery can pay for it.
+pub fn on_scan_event(
+    trigger: Trigger<ScanEvent>,
+    mut commands: Commands,
+    mut scanner_query: Query<(&mut Scanner, &Battery, &GlobalTransform)>,
+) {
+    let entity = trigger.event().entity;
+    let Ok((mut scanner, battery, g_transform)) = scanner_query.get_mut(entity) else {
+        return;
+    };
+
+    if !scanner.is_ready() || battery.current() < scanner.cost {
+        return;
+    }
+
+    scanner.cooldown.reset();
+    commands.trigger(DrainBatteryEvent {
+        entity,
+        drain: scanner.cost,
+    });
+
+    commands.spawn((
+        ScanPulse {
+            radius: 0.0,
+            range: scanner.range,
+            speed: scanner.speed,
+        },
+        Transform::from_translation(g_transform.translation().truncate().extend(0.0)),
+        Name::new("Scan Pulse"),
+    ));
+}
+
+pub fn tick_scanner_cooldowns(mut scanner_query: Query<&mut Scanner>, time: Res<Time>) {
+    for mut scanner in scanner_query.iter_mut() {
+        scanner.cooldown.tick(time.delta());
+    }
+}
+
+/// Expands scan pulses, scanning every asteroid they pass over.
+pub fn expand_scan_pulses(
+    mut commands: Commands,
+    mut pulse_query: Query<(Entity, &mut ScanPulse, &Transform)>,
+    asteroid_query: Query<(Entity, &Asteroid, &Mass, &GlobalTransform), Without<Scanned>>,
+    item_registry: Res<ItemRegistry>,
+    time: Res<Time>,
+) {
+    for (pulse_ent, mut pulse, transform) in pulse_query.iter_mut() {
+        pulse.radius = (pulse.radius + pulse.speed * time.delta_secs()).min(pulse.range);
+        let center = transform.translation.truncate();
+
+        for (asteroid_ent, asteroid, mass, g_transform) in asteroid_query.iter() {
+            if g_transform.translation().truncate().distance(center) > pulse.radius {
+                continue;
+            }
+
+            commands.entity(asteroid_ent).insert(Scanned {
+                estimated_value: estimate_value(asteroid, mass.0, &item_registry),
+            });
+        }
+
+        if pulse.radius >= pulse.range {
+            commands.entity(pulse_ent).despawn_recursive();
+        }
+    }
+}
+
+pub fn draw_scan_pulses(mut gizmos: Gizmos, pulse_query: Query<(&ScanPulse, &Transform)>) {
+    for (pulse, transform) in pulse_query.iter() {
+        // Fades out towards the end of its range.
+        let alpha = 1.0 - pulse.radius / pulse.range;
+
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            pulse.radius,
+            Color::from(AQUA).with_alpha(alpha),
+        );
+    }
+}
+
+/// Outlines scanned asteroids in the color of the material they are mostly made of.
+pub fn draw_scanned_outlines(
+    mut gizmos: Gizmos,
+    asteroid_query: Query<(&Asteroid, &GlobalTransform), With<Scanned>>,
+) {
+    for (asteroid, g_transform) in asteroid_query.iter() {
+        let vertices = &asteroid.polygon.vertices;
+        let outline = vertices
+            .iter()
+            .chain(vertices.first())
+            .map(|vertex| g_transform.transform_point(vertex.extend(0.0)).truncate());
+
+        gizmos.linestrip_2d(outline, Color::from(asteroid.primary_composition().color()));
+    }
+}
+
+/// Trade value of the ore the asteroid would give if all of it was collected.
+fn estimate_value(asteroid: &Asteroid, mass: f32, item_registry: &ItemRegistry) -> f32 {
+    asteroid
+        .composition
+        .percent_composition()
+        .iter()
+        .map(|(material, fraction)| {
+            item_registry.value_of(&InventoryItem::new(
+                material.item_id(),
+                Amount::Weight(OrderedFloat(fraction * mass)),
+            ))
+        })
+        .sum()
+}
@@ -4,21 +4,30 @@ use bevy::{
     ecs::{
         entity::Entity,
         event::EventReader,
-        query::Without,
+        query::{With, Without},
         system::{Commands, Query, Res, ResMut},
     },
     input::{keyboard::KeyCode, ButtonInput},
+    log::warn,
     prelude::NextState,
     sprite::{ColorMaterial, MeshMaterial2d},
 };
 
 use crate::{
+    health::components::Health,
     hexgrid::{
         components::HexTile,
         resources::{MouseHoverHex, SelectedHex},
     },
+    inventory::components::Inventory,
+    item_producer::components::{Producer, ProducerKind},
+    items::resources::ItemRegistry,
     player_input::resources::MouseWorldPosition,
-    space_station::modules::components::SpaceStationModuleType,
+    research::{components::Unlock, resources::ResearchUnlocks},
+    space_station::{
+        components::SpaceStation,
+        modules::components::{SpaceStationModuleType, MODULE_HEALTH},
+    },
     ui::context_clue::resources::{ContextClue, ContextClues},
     AppState,
 };
@@ -76,12 +85,43 @@ pub fn highlight_build_locations(
 pub fn handle_build_events(
     mut commands: Commands,
     mut build_events: EventReader<BuildSpaceStationModuleEvent>,
+    producer_query: Query<&Producer>,
+    station_query: Query<&Inventory, With<SpaceStation>>,
+    unlocks: Res<ResearchUnlocks>,
+    item_registry: Res<ItemRegistry>,
 ) {
     for build_event in build_events.read() {
+        if !unlocks.is_unlocked(&Unlock::Module(build_event.module_type)) {
+            warn!(
+                "{:?} modules have not been researched yet.",
+                build_event.module_type
+            );
+            continue;
+        }
+
+        // Rebuilding a producer as something else hands its reserved inputs back to the station,
+        // so they need room.
+        if let (Ok(producer), Ok(station)) = (
+            producer_query.get(build_event.entity),
+            station_query.get_single(),
+        ) {
+            let rebuilt =
+                ProducerKind::from_module_type(build_event.module_type) != Some(producer.kind);
+            let mut reserved = producer.queue.reserved.clone();
+            reserved.transfer_all(&mut station.clone(), &item_registry);
+
+            if rebuilt && !reserved.items.is_empty() {
+                warn!("No room in the station for the inputs reserved by this module.");
+                continue;
+            }
+        }
+
         // dbg!("{:?}", build_event.module_type);
+        // Rebuilding a module keeps the damage it already took.
         commands
             .entity(build_event.entity)
-            .insert(build_event.module_type);
+            .insert(build_event.module_type)
+            .insert_if_new(Health::with_maximum(MODULE_HEALTH));
     }
 }
 
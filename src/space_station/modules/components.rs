@@ -1,9 +1,13 @@
 use bevy::ecs::component::Component;
+use serde::Deserialize;
+
+/// Health of every station module when it's built.
+pub const MODULE_HEALTH: f32 = 1000.0;
 
 #[derive(Component)]
 pub struct SpaceStationModule;
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 pub enum SpaceStationModuleType {
     Core,
     Factory,
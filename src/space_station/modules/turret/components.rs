@@ -1,4 +1,142 @@
-use bevy::ecs::component::Component;
+use std::f32::consts::PI;
 
-#[derive(Component)]
-pub struct Turret;
+use bevy::prelude::*;
+
+use crate::PIXELS_PER_METER;
+
+/// A station turret firing at the most threatening target in its field of fire.
+#[derive(Component, Debug)]
+pub struct Turret {
+    /// Distance in pixels targets are engaged at.
+    pub range: f32,
+    /// Direction the turret's mount faces, away from the station.
+    pub facing: Vec2,
+    /// Half the angle of the field of fire around the facing, in radians.
+    pub arc: f32,
+    /// Radians per second the turret turns.
+    pub turn_rate: f32,
+    /// Station power used per burst.
+    pub power_per_shot: f32,
+}
+
+impl Turret {
+    pub fn new(facing: Vec2) -> Self {
+        Self {
+            range: 80.0 * PIXELS_PER_METER as f32,
+            facing,
+            arc: PI / 3.0,
+            turn_rate: PI,
+            power_per_shot: 5.0,
+        }
+    }
+
+    /// Whether the direction lies within the field of fire.
+    pub fn covers(&self, direction: Vec2) -> bool {
+        self.facing.dot(direction.normalize_or_zero()) >= self.arc.cos()
+    }
+}
+
+/// What a turret can shoot at, from lowest to highest priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TargetKind {
+    /// An asteroid on a collision course with the station.
+    Asteroid,
+    Enemy,
+}
+
+/// Where a projectile fired now at `projectile_speed` meets a target moving at a constant velocity,
+/// or `None` if it can't catch up.
+pub fn intercept_point(
+    shooter: Vec2,
+    target: Vec2,
+    target_velocity: Vec2,
+    projectile_speed: f32,
+) -> Option<Vec2> {
+    let offset = target - shooter;
+    let a = target_velocity.length_squared() - projectile_speed * projectile_speed;
+    let b = 2.0 * offset.dot(target_velocity);
+    let c = offset.length_squared();
+
+    let time = if a.abs() < f32::EPSILON {
+        // The target moves as fast as the projectile, it's only caught when coming closer.
+        if b >= 0.0 {
+            return None;
+        }
+        -c / b
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+            .into_iter()
+            .filter(|time| *time >= 0.0)
+            .reduce(f32::min)?
+    };
+
+    Some(target + target_velocity * time)
+}
+
+/// Seconds until a body at `position` moving at `velocity`, both relative to the station,
+/// comes within `radius` of it, or `None` if it passes by.
+pub fn time_to_collision(position: Vec2, velocity: Vec2, radius: f32) -> Option<f32> {
+    let c = position.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    let a = velocity.length_squared();
+    let b = 2.0 * position.dot(velocity);
+    let discriminant = b * b - 4.0 * a * c;
+    if a <= 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let time = (-b - discriminant.sqrt()) / (2.0 * a);
+    (time >= 0.0).then_some(time)
+}
+
+#[test]
+fn test_intercept_point() {
+    // A target standing still is hit where it is.
+    let still = intercept_point(Vec2::ZERO, Vec2::new(100.0, 0.0), Vec2::ZERO, 50.0);
+    assert_eq!(still, Some(Vec2::new(100.0, 0.0)));
+
+    // Crossing at 30 with a projectile speed of 50 is a 3-4-5 triangle: the target
+    // moves 3 units for every 4 the projectile closes in, meeting after 2.5 seconds.
+    let crossing = intercept_point(
+        Vec2::ZERO,
+        Vec2::new(100.0, 0.0),
+        Vec2::new(0.0, 30.0),
+        50.0,
+    )
+    .unwrap();
+    assert!((crossing - Vec2::new(100.0, 75.0)).length() < 1e-3);
+    assert!((crossing.length() / 50.0 - 2.5).abs() < 1e-4);
+
+    // A target running away faster than the projectile can't be caught.
+    let fleeing = intercept_point(
+        Vec2::ZERO,
+        Vec2::new(100.0, 0.0),
+        Vec2::new(80.0, 0.0),
+        50.0,
+    );
+    assert_eq!(fleeing, None);
+}
+
+#[test]
+fn test_time_to_collision() {
+    let incoming = time_to_collision(Vec2::new(100.0, 0.0), Vec2::new(-10.0, 0.0), 20.0);
+    assert_eq!(incoming, Some(8.0));
+
+    let passing = time_to_collision(Vec2::new(100.0, 50.0), Vec2::new(-10.0, 0.0), 20.0);
+    assert_eq!(passing, None);
+
+    let leaving = time_to_collision(Vec2::new(100.0, 0.0), Vec2::new(10.0, 0.0), 20.0);
+    assert_eq!(leaving, None);
+
+    let inside = time_to_collision(Vec2::new(10.0, 0.0), Vec2::ZERO, 20.0);
+    assert_eq!(inside, Some(0.0));
+}
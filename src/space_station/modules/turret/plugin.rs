@@ -1,11 +1,12 @@
 use bevy::app::{App, Plugin, Update};
+use bevy::ecs::schedule::IntoSystemConfigs;
 
-use super::systems::update_turret_weapons;
+use super::systems::{add_turret_mounts, update_turret_weapons};
 
 pub struct SpaceStationTurretPlugin;
 
 impl Plugin for SpaceStationTurretPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, update_turret_weapons);
+        app.add_systems(Update, (add_turret_mounts, update_turret_weapons).chain());
     }
 }
@@ -1,35 +1,212 @@
-use bevy::{
-    ecs::{entity::Entity, query::With, system::Query},
-    hierarchy::Parent,
-    math::{Quat, Vec2, Vec3},
-    transform::components::{GlobalTransform, Transform},
+use avian2d::prelude::LinearVelocity;
+use bevy::{color::palettes::css::WHITE, prelude::*};
+
+use crate::{
+    ai::components::Enemy,
+    asteroid::components::Asteroid,
+    battery::{components::Battery, events::DrainBatteryEvent},
+    collectible::components::Collectible,
+    projectile::{
+        components::{Faction, Weapon},
+        events::FireProjectileEvent,
+        resources::WeaponRegistry,
+    },
+    space_station::{components::SpaceStation, modules::components::SpaceStationModuleType},
+    PIXELS_PER_METER,
 };
 
-use crate::ai::components::Enemy;
+use super::components::{intercept_point, time_to_collision, TargetKind, Turret};
 
-use super::components::Turret;
+/// Asteroids passing closer than this to the station are on a collision course.
+const STATION_THREAT_RADIUS: f32 = 30.0 * PIXELS_PER_METER as f32;
+/// Asteroids further out than this many seconds from hitting the station are ignored.
+const ASTEROID_THREAT_TIME: f32 = 15.0;
+/// Radians the turret may be off its aim and still fire.
+const AIM_TOLERANCE: f32 = 0.05;
+/// Weapon definition every turret is armed with.
+const TURRET_WEAPON: &str = "turret_cannon";
 
-pub fn update_turret_weapons(
-    enemies: Query<(Entity, &GlobalTransform), With<Enemy>>,
-    mut turrets: Query<(&Parent, &mut Transform, &GlobalTransform), With<Turret>>,
+/// Mounts a turret on every module that becomes a turret module, facing away from the station,
+/// and takes it down again when the module is turned into something else.
+pub fn add_turret_mounts(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    module_query: Query<
+        (
+            Entity,
+            &SpaceStationModuleType,
+            &GlobalTransform,
+            Option<&Children>,
+        ),
+        Changed<SpaceStationModuleType>,
+    >,
+    turret_query: Query<(), With<Turret>>,
+    station_query: Query<&GlobalTransform, With<SpaceStation>>,
 ) {
-    for (_, mut turret_transform, gt) in turrets.iter_mut() {
-        let mut nearest_enemy: Option<(Entity, f32, Vec2)> = None;
+    let station_position = station_query
+        .get_single()
+        .map_or(Vec2::ZERO, |station_gt| station_gt.translation().truncate());
 
-        for (enemy_entitiy, enemy_gt) in enemies.iter() {
-            let distance = (enemy_gt.translation().truncate()
-                - turret_transform.translation.truncate())
-            .length();
+    for (module_ent, module_type, module_gt, children) in module_query.iter() {
+        let mut turrets = children
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|child| turret_query.contains(*child));
 
-            if nearest_enemy.is_none() || distance < nearest_enemy.unwrap().1 {
-                nearest_enemy = Some((enemy_entitiy, distance, enemy_gt.translation().truncate()));
+        if *module_type != SpaceStationModuleType::Turret {
+            for turret_ent in turrets {
+                commands.entity(turret_ent).despawn_recursive();
             }
+            continue;
         }
 
-        if let Some(nearest_enemy) = nearest_enemy {
-            let trajectory = (nearest_enemy.2.extend(0.0) - gt.translation()).normalize();
-            let rotate_to_enemy = Quat::from_rotation_arc(Vec3::X, trajectory);
-            turret_transform.rotation = rotate_to_enemy;
+        if turrets.next().is_some() {
+            continue;
         }
+
+        let facing = (module_gt.translation().truncate() - station_position)
+            .try_normalize()
+            .unwrap_or(Vec2::Y);
+
+        let barrel = Rectangle::new(20.0, 1.0);
+        let body = RegularPolygon::new(20.0, 8);
+
+        commands.entity(module_ent).with_children(|parent| {
+            parent
+                .spawn((
+                    Turret::new(facing),
+                    Weapon::new(TURRET_WEAPON),
+                    Faction::Player,
+                    Mesh2d(meshes.add(body)),
+                    MeshMaterial2d(materials.add(Color::from(WHITE))),
+                    Name::new("Turret"),
+                    Transform::from_xyz(0.0, 0.0, 1.0)
+                        .with_rotation(Quat::from_rotation_arc_2d(Vec2::X, facing)),
+                ))
+                .with_child((
+                    Mesh2d(meshes.add(barrel)),
+                    MeshMaterial2d(materials.add(Color::from(WHITE))),
+                    Name::new("Turret Barrel"),
+                    Transform::from_xyz(0.0, 0.0, 1.0),
+                ));
+        });
+    }
+}
+
+/// Turns every turret towards where its highest priority target will be, and fires once on target.
+/// Shots are paid for from the station battery, turrets stay quiet when it runs low.
+#[allow(clippy::too_many_arguments)]
+pub fn update_turret_weapons(
+    mut commands: Commands,
+    mut turrets: Query<(
+        Entity,
+        &Turret,
+        &Weapon,
+        &Parent,
+        &mut Transform,
+        &GlobalTransform,
+    )>,
+    parent_query: Query<&GlobalTransform>,
+    enemy_query: Query<(&GlobalTransform, Option<&LinearVelocity>), With<Enemy>>,
+    asteroid_query: Query<
+        (&GlobalTransform, &LinearVelocity),
+        (With<Asteroid>, Without<Collectible>),
+    >,
+    station_query: Query<(Entity, &Battery, &GlobalTransform), With<SpaceStation>>,
+    mut fire_projectile_events: EventWriter<FireProjectileEvent>,
+    registry: Res<WeaponRegistry>,
+    time: Res<Time>,
+) {
+    let Ok((station_ent, battery, station_gt)) = station_query.get_single() else {
+        return;
+    };
+    let station_position = station_gt.translation().truncate();
+
+    // Drains only reach the battery after this system, so keep track of what the shots used up.
+    let mut available_power = battery.current();
+
+    let enemies = enemy_query.iter().map(|(gt, velocity)| {
+        let position = gt.translation().truncate();
+        let velocity = velocity.map_or(Vec2::ZERO, |velocity| velocity.0.as_vec2());
+        (TargetKind::Enemy, position, velocity)
+    });
+
+    let asteroids = asteroid_query
+        .iter()
+        .map(|(gt, velocity)| {
+            let position = gt.translation().truncate();
+            (TargetKind::Asteroid, position, velocity.0.as_vec2())
+        })
+        .filter(|(_, position, velocity)| {
+            time_to_collision(
+                *position - station_position,
+                *velocity,
+                STATION_THREAT_RADIUS,
+            )
+            .is_some_and(|time| time <= ASTEROID_THREAT_TIME)
+        });
+
+    let targets: Vec<(TargetKind, Vec2, Vec2)> = enemies.chain(asteroids).collect();
+
+    for (turret_ent, turret, weapon, parent, mut transform, gt) in turrets.iter_mut() {
+        let Some(definition) = registry.get(&weapon.definition) else {
+            continue;
+        };
+
+        let position = gt.translation().truncate();
+
+        // Highest priority first, the closest target among equals.
+        let aim = targets
+            .iter()
+            .filter_map(|(kind, target, velocity)| {
+                let aim = intercept_point(position, *target, *velocity, definition.speed)?;
+                let distance = position.distance(aim);
+                (distance <= turret.range && turret.covers(aim - position))
+                    .then_some((*kind, distance, aim))
+            })
+            .min_by(|a, b| b.0.cmp(&a.0).then(a.1.total_cmp(&b.1)))
+            .map(|(_, _, aim)| aim);
+
+        let Some(aim) = aim else {
+            continue;
+        };
+
+        let rotation = gt.compute_transform().rotation;
+        let heading = (rotation * Vec3::X).truncate();
+        let direction = (aim - position).normalize();
+        let angle = heading.perp_dot(direction).atan2(heading.dot(direction));
+        let max_turn = turret.turn_rate * time.delta_secs();
+        let turn = angle.clamp(-max_turn, max_turn);
+
+        // The turret is a child of its module, so rotate in world space and convert back.
+        let rotation = Quat::from_rotation_z(turn) * rotation;
+        let parent_rotation = parent_query
+            .get(parent.get())
+            .map_or(Quat::IDENTITY, |parent_gt| {
+                parent_gt.compute_transform().rotation
+            });
+        transform.rotation = parent_rotation.inverse() * rotation;
+
+        if (angle - turn).abs() > AIM_TOLERANCE
+            || !weapon.is_ready()
+            || available_power < turret.power_per_shot
+        {
+            continue;
+        }
+
+        available_power -= turret.power_per_shot;
+
+        commands.trigger(DrainBatteryEvent {
+            entity: station_ent,
+            drain: turret.power_per_shot,
+        });
+
+        let heading = (rotation * Vec3::X).truncate();
+        fire_projectile_events.send(FireProjectileEvent {
+            entity: turret_ent,
+            direction: heading,
+        });
     }
 }
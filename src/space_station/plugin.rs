@@ -8,8 +8,8 @@ use super::modules::turret::plugin::SpaceStationTurretPlugin;
 use super::resources::CanDeposit;
 
 use super::systems::{
-    color_space_station_modules, handle_space_station_collision_event, init_space_station_core,
-    init_space_station_module_material_map, init_space_station_turret,
+    color_space_station_modules, generate_station_power, handle_space_station_collision_event,
+    init_space_station_core, init_space_station_module_material_map, init_space_station_turret,
     repel_asteroids_from_space_station,
 };
 
@@ -34,6 +34,7 @@ impl Plugin for SpaceStationPlugin {
                     repel_asteroids_from_space_station,
                     handle_space_station_collision_event,
                     color_space_station_modules,
+                    generate_station_power,
                 ),
             );
     }
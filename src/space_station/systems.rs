@@ -1,7 +1,7 @@
 use avian2d::prelude::*;
 use bevy::color::palettes::css::PINK;
 use bevy::{
-    color::palettes::css::{DARK_GRAY, ORANGE_RED, TEAL, WHITE},
+    color::palettes::css::{DARK_GRAY, ORANGE_RED, TEAL},
     prelude::*,
 };
 // use bevy_prototype_lyon::prelude::*;
@@ -9,8 +9,11 @@ use hexx::Hex;
 
 use crate::{
     asteroid::components::Asteroid,
-    battery::events::ChargeBatteryEvent,
-    health::{components::Health, events::RepairEvent},
+    battery::{components::Battery, events::ChargeBatteryEvent},
+    health::{
+        components::{Health, Shield},
+        events::RepairEvent,
+    },
     hexgrid::{
         components::{BuildingType, HexTile},
         resources::HexGridMap,
@@ -19,6 +22,7 @@ use crate::{
         components::{Capacity, Inventory},
         systems::attach_inventory_to_entity,
     },
+    items::resources::ItemRegistry,
     player::components::Player,
     ui::context_clue::resources::{ContextClue, ContextClues},
     PIXELS_PER_METER,
@@ -26,13 +30,13 @@ use crate::{
 
 use super::{
     components::SpaceStation,
-    modules::{
-        components::{SpaceStationModule, SpaceStationModuleType},
-        turret::components::Turret,
-    },
+    modules::components::{SpaceStationModule, SpaceStationModuleType, MODULE_HEALTH},
     resources::{CanDeposit, PlayerHoveringSpaceStationModule, SpaceStationModuleMaterialMap},
 };
 
+/// Battery charge the station generates per second.
+const STATION_POWER_GENERATION: f32 = 20.0;
+
 pub fn init_space_station_module_material_map(
     mut commands: Commands,
     mut materials: ResMut<Assets<ColorMaterial>>,
@@ -46,11 +50,17 @@ pub fn init_space_station_module_material_map(
     });
 }
 
-pub fn init_space_station_core(mut commands: Commands, hex_grid_map: Res<HexGridMap>) {
+pub fn init_space_station_core(
+    mut commands: Commands,
+    hex_grid_map: Res<HexGridMap>,
+    item_registry: Res<ItemRegistry>,
+) {
     if let Some(origin_hex_ent) = hex_grid_map.entities.get(&Hex::ORIGIN).copied() {
         commands.entity(origin_hex_ent).insert((
             SpaceStationModuleType::Core,
             Health::with_maximum(1000.0),
+            Shield::with_maximum(500.0, 20.0),
+            Battery::new(),
             SpaceStation,
             Name::new("Base Station"),
         ));
@@ -62,55 +72,36 @@ pub fn init_space_station_core(mut commands: Commands, hex_grid_map: Res<HexGrid
                 capacity: Capacity {
                     maximum: 2000.0.into(),
                 },
+                slots: None,
             },
             origin_hex_ent,
+            &item_registry,
         )
     }
 }
 
-pub fn init_space_station_turret(
+/// Turret modules get their turret mounted by `add_turret_mounts`.
+pub fn init_space_station_turret(mut commands: Commands, hex_grid_map: Res<HexGridMap>) {
+    if let Some(origin_hex_ent) = hex_grid_map.entities.get(&Hex::new(0, 1)).copied() {
+        commands.entity(origin_hex_ent).insert((
+            SpaceStationModuleType::Turret,
+            Health::with_maximum(MODULE_HEALTH),
+            Name::new("Space Station Turret"),
+        ));
+    }
+}
+
+/// Charges the station battery, which powers the station's turrets.
+pub fn generate_station_power(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    gizmos: Gizmos,
-    hex_grid_map: Res<HexGridMap>,
+    station_query: Query<Entity, With<SpaceStation>>,
+    time: Res<Time>,
 ) {
-    if let Some(origin_hex_ent) = hex_grid_map.entities.get(&Hex::new(0, 1)).copied() {
-        commands
-            .entity(origin_hex_ent)
-            .insert((
-                SpaceStationModuleType::Turret,
-                Health::with_maximum(1000.0),
-                Name::new("Space Station Turret"),
-            ))
-            .with_children(|parent| {
-                let barrel = Rectangle::new(20.0, 1.0);
-                let body = RegularPolygon::new(20.0, 8);
-
-                parent
-                    .spawn((
-                        Turret,
-                        Mesh2d(meshes.add(body)),
-                        MeshMaterial2d(materials.add(Color::from(WHITE))),
-                        Name::new("Turret"),
-                        Transform::from_xyz(0.0, 0.0, 1.0),
-                    ))
-                    .with_child((
-                        // Turret,
-                        Mesh2d(meshes.add(barrel)),
-                        MeshMaterial2d(materials.add(Color::from(WHITE))),
-                        Name::new("Turret Barrel"),
-                        Transform::from_xyz(0.0, 0.0, 1.0),
-                    ));
-
-                // parent.spawn((
-                //     Turret,
-                //     Mesh2d(meshes.add(barrel)),
-                //     MeshMaterial2d(materials.add(Color::from(WHITE))),
-                //     Name::new("Turret"),
-                //     Transform::from_xyz(0.0, 0.0, 1.0)
-                // ));
-            });
+    for station_ent in station_query.iter() {
+        commands.trigger(ChargeBatteryEvent {
+            entity: station_ent,
+            charge: STATION_POWER_GENERATION * time.delta_secs(),
+        });
     }
 }
 
@@ -4,6 +4,8 @@ use bevy_egui::{egui::Window, EguiContexts};
 
 use crate::camera::components::GameCamera;
 use crate::hexgrid::resources::SelectedHex;
+use crate::research::components::Unlock;
+use crate::research::resources::ResearchUnlocks;
 use crate::space_station::build_mode::events::BuildSpaceStationModuleEvent;
 use crate::space_station::modules::components::SpaceStationModuleType;
 
@@ -13,6 +15,7 @@ pub fn ui_build_mode(
     entity_g_t_q: Query<&GlobalTransform, Without<GameCamera>>,
     camera: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
     mut build_events: EventWriter<BuildSpaceStationModuleEvent>,
+    unlocks: Res<ResearchUnlocks>,
 ) {
     if let Some(selected) = selected.entity {
         if let Ok(gt) = entity_g_t_q.get(selected) {
@@ -38,7 +41,9 @@ pub fn ui_build_mode(
                                     ("Turret", SpaceStationModuleType::Turret),
                                 ];
 
-                                for button in buttons {
+                                for button in buttons.into_iter().filter(|(_, module_type)| {
+                                    unlocks.is_unlocked(&Unlock::Module(*module_type))
+                                }) {
                                     if ui.button(button.0).clicked() {
                                         println!("SEND EVENT");
                                         build_events.send(BuildSpaceStationModuleEvent {
@@ -7,7 +7,10 @@ pub mod context_clue;
 pub mod damage_indicator;
 pub mod mouse_coordinates;
 pub mod mouse_hover_context;
+pub mod production_queue;
+pub mod research;
 pub mod ship_hover_context;
 pub mod ship_information;
 pub mod ship_inventory;
 pub mod space_station_menu;
+pub mod wave_warning;
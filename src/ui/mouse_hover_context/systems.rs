@@ -16,6 +16,7 @@ use crate::{
     asteroid::components::Asteroid,
     health::components::Health,
     player_input::resources::{MouseScreenPosition, MouseWorldPosition},
+    scanner::components::Scanned,
     space_station::modules::components::SpaceStationModuleType,
     ui::helpers::progress_string,
 };
@@ -57,6 +58,7 @@ pub fn ui_mouse_hover_context(
         Option<&Health>,
         Option<&Mass>,
         Option<&SpaceStationModuleType>,
+        Option<&Scanned>,
     )>,
 ) {
     if let Some(hover_context_ent) = mouse_hover_context.0 {
@@ -72,7 +74,7 @@ pub fn ui_mouse_hover_context(
             .title_bar(false)
             .resizable(false)
             .show(ctx.ctx_mut(), |ui| {
-                if let Ok((_ent, name, asteroid, health, mass, module_type)) =
+                if let Ok((_ent, name, asteroid, health, mass, module_type, scanned)) =
                     ent_query.get(hover_context_ent)
                 {
                     ui.group(|ui| {
@@ -89,8 +91,17 @@ pub fn ui_mouse_hover_context(
                                 ui.label(format!("Mass: {}Kgs", m.0));
                             }
 
-                            ui.label("Composition:");
-                            ui.label(format!("{:?}", asteroid.composition));
+                            // Composition is only known once the asteroid has been scanned.
+                            if let Some(scanned) = scanned {
+                                ui.label("Composition:");
+                                ui.label(format!("{:?}", asteroid.composition));
+                                ui.label(format!(
+                                    "Estimated Value: {:.0} credits",
+                                    scanned.estimated_value
+                                ));
+                            } else {
+                                ui.label("Composition: unknown");
+                            }
                         }
 
                         if let Some(module_type) = module_type {
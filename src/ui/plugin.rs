@@ -9,8 +9,9 @@ use super::{
     damage_indicator::plugin::DamageIndicatorPlugin, helpers::absorb_egui_inputs,
     mouse_coordinates::plugin::MouseCoordinatesPlugin,
     mouse_hover_context::plugin::MouseHoverContextPlugin,
+    production_queue::plugin::ProductionQueueUIPlugin, research::plugin::ResearchUIPlugin,
     ship_information::plugin::ShipInformationPlugin, ship_inventory::plugin::ShipInventoryPlugin,
-    space_station_menu::plugin::SpaceStationMenu,
+    space_station_menu::plugin::SpaceStationMenu, wave_warning::plugin::WaveWarningPlugin,
 };
 
 pub struct GameUIPlugin;
@@ -30,7 +31,10 @@ impl Plugin for GameUIPlugin {
                 MouseCoordinatesPlugin,
                 DamageIndicatorPlugin,
                 // ShipHoverContext
+                ProductionQueueUIPlugin,
                 BuildModeUIPlugin,
+                ResearchUIPlugin,
+                WaveWarningPlugin,
             ))
             .add_systems(
                 PreUpdate,
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use super::{
+    resources::{QueueRepeatSelection, SelectedProducer},
+    systems::{select_producer_module, ui_production_queue},
+};
+
+pub struct ProductionQueueUIPlugin;
+
+impl Plugin for ProductionQueueUIPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<QueueRepeatSelection>()
+            .init_resource::<SelectedProducer>()
+            .add_systems(
+                Update,
+                (select_producer_module, ui_production_queue)
+                    .chain()
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::{Entity, Resource};
+
+/// How many times a job queued from the production queue window should be produced.
+#[derive(Resource, Debug)]
+pub struct QueueRepeatSelection {
+    pub count: u32,
+    pub forever: bool,
+}
+
+impl Default for QueueRepeatSelection {
+    fn default() -> Self {
+        Self {
+            count: 1,
+            forever: false,
+        }
+    }
+}
+
+/// The station module with a producer whose production queue window is open.
+#[derive(Resource, Debug, Default)]
+pub struct SelectedProducer(pub Option<Entity>);
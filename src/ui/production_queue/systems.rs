@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{Align2, Button, DragValue, Ui, Vec2, Window},
+    EguiContexts,
+};
+
+use crate::{
+    hexgrid::resources::SelectedHex,
+    inventory::components::Inventory,
+    item_producer::{
+        components::{JobRepeat, Producer},
+        events::ProductionQueueEvent,
+        ItemProducer,
+    },
+    space_station::components::SpaceStation,
+    ui::helpers::progress_string,
+};
+
+use super::resources::{QueueRepeatSelection, SelectedProducer};
+
+/// Opens the production queue of the station module the player clicks on,
+/// and closes it when they click a module without a producer.
+/// Clicks on a window don't change the selection.
+pub fn select_producer_module(
+    mut ctx: EguiContexts,
+    selected_hex: Res<SelectedHex>,
+    producer_query: Query<(), With<Producer>>,
+    mut selected_producer: ResMut<SelectedProducer>,
+) {
+    if !selected_hex.is_changed() || ctx.ctx_mut().is_pointer_over_area() {
+        return;
+    }
+
+    selected_producer.0 = selected_hex
+        .entity
+        .filter(|entity| producer_query.contains(*entity));
+}
+
+pub fn ui_production_queue(
+    mut ctx: EguiContexts,
+    mut selected_producer: ResMut<SelectedProducer>,
+    producer_query: Query<&Producer>,
+    inventory_query: Query<&Inventory, With<SpaceStation>>,
+    mut queue_events: EventWriter<ProductionQueueEvent>,
+    mut repeat_selection: ResMut<QueueRepeatSelection>,
+) {
+    let Some(entity) = selected_producer.0 else {
+        return;
+    };
+    // The module may have been rebuilt as something that doesn't produce.
+    let Ok(producer) = producer_query.get(entity) else {
+        selected_producer.0 = None;
+        return;
+    };
+    let Ok(inventory) = inventory_query.get_single() else {
+        return;
+    };
+
+    let mut open = true;
+
+    Window::new(format!("{:?}", producer.kind))
+        .anchor(Align2::RIGHT_TOP, Vec2 { x: 0.0, y: 40.0 })
+        .open(&mut open)
+        .show(ctx.ctx_mut(), |ui| {
+            production_queue_ui(
+                ui,
+                entity,
+                producer,
+                inventory,
+                &mut repeat_selection,
+                &mut queue_events,
+            );
+        });
+
+    if !open {
+        selected_producer.0 = None;
+    }
+}
+
+/// Shows the active job, the waiting jobs and the recipes of a producer,
+/// sending ProductionQueueEvents for the player's changes to the queue.
+fn production_queue_ui(
+    ui: &mut Ui,
+    entity: Entity,
+    producer: &impl ItemProducer,
+    inventory: &Inventory,
+    repeat_selection: &mut QueueRepeatSelection,
+    queue_events: &mut EventWriter<ProductionQueueEvent>,
+) {
+    let queue = producer.queue();
+
+    ui.group(|ui| {
+        ui.heading("Processing:");
+
+        if let Some(active) = &queue.active {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{:?} ({:?})",
+                    active.job.recipe.outputs, active.job.repeat
+                ));
+                if ui.button("Cancel").clicked() {
+                    queue_events.send(ProductionQueueEvent::CancelActive { entity });
+                }
+            });
+            ui.label(format!(
+                "Time Remaining: {:.1} sec",
+                queue.remaining_time().unwrap_or_default()
+            ));
+            ui.label(progress_string(queue.progress().unwrap_or_default()));
+        } else if let Some(next) = queue.jobs.front() {
+            ui.label(format!(
+                "Waiting for materials: {:?}",
+                next.recipe.items_required
+            ));
+        } else {
+            ui.label("Idle");
+        }
+    });
+
+    ui.group(|ui| {
+        ui.heading(format!(
+            "Queue ({}/{}):",
+            queue.jobs.len(),
+            queue.max_length
+        ));
+
+        for (index, job) in queue.jobs.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(format!("{:?} ({:?})", job.recipe.outputs, job.repeat));
+
+                if index > 0 && ui.button("Up").clicked() {
+                    queue_events.send(ProductionQueueEvent::Reorder {
+                        entity,
+                        from: index,
+                        to: index - 1,
+                    });
+                }
+                if index + 1 < queue.jobs.len() && ui.button("Down").clicked() {
+                    queue_events.send(ProductionQueueEvent::Reorder {
+                        entity,
+                        from: index,
+                        to: index + 1,
+                    });
+                }
+                if ui.button("Cancel").clicked() {
+                    queue_events.send(ProductionQueueEvent::Cancel { entity, index });
+                }
+            });
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Repeat:");
+        ui.add_enabled(
+            !repeat_selection.forever,
+            DragValue::new(&mut repeat_selection.count).range(1..=99),
+        );
+        ui.checkbox(&mut repeat_selection.forever, "Forever");
+    });
+
+    ui.heading("Recipes:");
+    for recipe in producer.recipes() {
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{:?}", recipe.outputs));
+                    ui.label(format!("Requires: {:?}", recipe.items_required));
+                    if inventory.has_items(recipe.items_required.clone()) {
+                        ui.label("👍");
+                    }
+                });
+
+                for byproduct in recipe.byproducts.iter() {
+                    ui.label(format!(
+                        "Byproduct: {:?} ({:.0}% chance)",
+                        byproduct.item,
+                        byproduct.chance * 100.0
+                    ));
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(format!("Time Required: {:.1} sec", recipe.time_required));
+
+                    if ui
+                        .add_enabled(!queue.is_full(), Button::new("Queue"))
+                        .clicked()
+                    {
+                        let repeat = if repeat_selection.forever {
+                            JobRepeat::Forever
+                        } else {
+                            JobRepeat::Times(repeat_selection.count)
+                        };
+
+                        queue_events.send(ProductionQueueEvent::Enqueue {
+                            entity,
+                            recipe: recipe.clone(),
+                            repeat,
+                        });
+                    }
+                })
+            });
+        });
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::app::{App, Plugin, Update};
+
+use super::systems::ui_research;
+
+pub struct ResearchUIPlugin;
+
+impl Plugin for ResearchUIPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, ui_research);
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{Align2, Button, ScrollArea, Vec2, Window},
+    EguiContexts,
+};
+
+use crate::{
+    inventory::components::Inventory,
+    items::resources::ItemRegistry,
+    research::{
+        components::TechTree,
+        events::ResearchEvent,
+        resources::{ResearchState, TechTreeHandle},
+    },
+    space_station::components::SpaceStation,
+    ui::helpers::progress_string,
+};
+
+pub fn ui_research(
+    mut ctx: EguiContexts,
+    research: Res<ResearchState>,
+    tech_trees: Res<Assets<TechTree>>,
+    tech_tree_handle: Option<Res<TechTreeHandle>>,
+    inventory_query: Query<&Inventory, With<SpaceStation>>,
+    item_registry: Res<ItemRegistry>,
+    mut research_events: EventWriter<ResearchEvent>,
+) {
+    let Some(tree) = tech_tree_handle.and_then(|handle| tech_trees.get(&handle.0)) else {
+        return;
+    };
+    let Ok(inventory) = inventory_query.get_single() else {
+        return;
+    };
+
+    Window::new("Research")
+        .anchor(Align2::LEFT_BOTTOM, Vec2 { x: 0.0, y: 0.0 })
+        .default_open(false)
+        .show(ctx.ctx_mut(), |ui| {
+            ui.group(|ui| {
+                ui.heading("Researching:");
+
+                if let Some(active) = &research.active {
+                    let name = tree
+                        .get(&active.tech)
+                        .map_or(active.tech.as_str(), |node| node.name.as_str());
+
+                    ui.horizontal(|ui| {
+                        ui.label(name);
+                        if ui.button("Cancel").clicked() {
+                            research_events.send(ResearchEvent::Cancel);
+                        }
+                    });
+                    ui.label(format!(
+                        "Time Remaining: {:.1} sec",
+                        research.remaining_time().unwrap_or_default()
+                    ));
+                    ui.label(progress_string(research.progress().unwrap_or_default()));
+                } else {
+                    ui.label("Idle");
+                }
+            });
+
+            ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                for node in tree.nodes.iter() {
+                    ui.group(|ui| {
+                        ui.vertical(|ui| {
+                            let status = if research.is_completed(&node.id) {
+                                "Researched"
+                            } else if research.is_researching(&node.id) {
+                                "Researching"
+                            } else if research.is_available(node) {
+                                "Available"
+                            } else {
+                                "Locked"
+                            };
+
+                            ui.heading(format!("{} ({})", node.name, status));
+                            if !node.description.is_empty() {
+                                ui.label(node.description.as_str());
+                            }
+
+                            if !node.prerequisites.is_empty() {
+                                let prerequisites: Vec<_> = node
+                                    .prerequisites
+                                    .iter()
+                                    .map(|id| {
+                                        tree.get(id).map_or(id.as_str(), |node| node.name.as_str())
+                                    })
+                                    .collect();
+                                ui.label(format!("Requires: {}", prerequisites.join(", ")));
+                            }
+
+                            let cost = node.items_required(&item_registry);
+                            for item in cost.iter() {
+                                ui.label(format!("Cost: {}", item_registry.describe(item)));
+                            }
+
+                            for unlock in node.unlocks.iter() {
+                                ui.label(format!("Unlocks: {:?}", unlock));
+                            }
+
+                            ui.horizontal(|ui| {
+                                ui.label(format!("Time Required: {:.1} sec", node.time));
+
+                                let can_start = research.active.is_none()
+                                    && research.is_available(node)
+                                    && inventory.has_items(cost.clone());
+
+                                if ui.add_enabled(can_start, Button::new("Research")).clicked() {
+                                    research_events.send(ResearchEvent::Start {
+                                        tech: node.id.clone(),
+                                    });
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+        });
+}
@@ -5,15 +5,11 @@ use bevy_egui::{
 };
 
 use crate::{
-    events::CraftEvent,
-    factory::Factory,
     hexgrid::components::BuildingType,
     hexgrid::events::BuildHexBuildingEvent,
     hexgrid::resources::PlayerHoveringBuilding,
     inventory::components::Inventory,
-    refinery::{Refinery, SmeltEvent},
     space_station::components::SpaceStation,
-    ui::helpers::progress_string,
 };
 
 pub fn ui_ship_hover_context(
@@ -22,10 +18,6 @@ pub fn ui_ship_hover_context(
     player_hovering_building: Res<PlayerHoveringBuilding>,
     // player_query: Query<(&Player, &UpgradesComponent)>,
     inventory_query: Query<&Inventory, With<SpaceStation>>,
-    factory_query: Query<&Factory>,
-    refinery_query: Query<&Refinery>,
-    mut craft_events: EventWriter<CraftEvent>,
-    mut smelt_events: EventWriter<SmeltEvent>,
     // mut upgrade_events: EventWriter<UpgradeEvent>,
     mut build_event: EventWriter<BuildHexBuildingEvent>,
 ) {
@@ -66,109 +58,8 @@ pub fn ui_ship_hover_context(
                             }
                         });
                     }
-                    BuildingType::Factory => {
-                        ui.group(|ui| {
-                            let factory = factory_query.single();
-
-                            if let Some(recipe) = &factory.currently_processing {
-                                ui.group(|ui| {
-                                    ui.heading("Factory Processing:");
-                                    ui.label(format!(
-                                        "Currently Crafting: {:?}",
-                                        recipe.item_created
-                                    ));
-                                    ui.label(format!(
-                                        "Time Remaining: {:.1} sec",
-                                        factory.remaining_processing_time
-                                    ));
-                                    ui.label(progress_string(
-                                        (recipe.time_required - factory.remaining_processing_time)
-                                            / recipe.time_required,
-                                    ));
-                                });
-                            }
-
-                            ui.heading("Factory Recipes:");
-                            for recipe in &factory.recipes {
-                                ui.group(|ui| {
-                                    ui.vertical(|ui| {
-                                        ui.horizontal(|ui| {
-                                            ui.label(format!("{:?}", recipe.item_created));
-
-                                            ui.label(format!(
-                                                "Requires: {:?}",
-                                                recipe.items_required
-                                            ));
-                                            if inventory.has_items(recipe.items_required.clone()) {
-                                                ui.label("👍");
-                                            }
-                                        });
-
-                                        ui.horizontal(|ui| {
-                                            ui.label(format!(
-                                                "Time Required: {:.1} sec",
-                                                recipe.time_required
-                                            ));
-
-                                            if ui.button("Craft").clicked() {
-                                                craft_events.send(CraftEvent(recipe.clone()));
-                                            }
-                                        })
-                                    });
-                                });
-                            }
-                        });
-                    }
-                    BuildingType::Refinery => {
-                        ui.group(|ui| {
-                            let refinery = refinery_query.single();
-
-                            if let Some(recipe) = &refinery.currently_processing {
-                                ui.group(|ui| {
-                                    ui.heading("Refinery Processing:");
-
-                                    ui.label(format!("Currently Refining: {:?}", recipe));
-                                    ui.label(format!(
-                                        "Time Remaining: {:.1} sec",
-                                        refinery.remaining_processing_time
-                                    ));
-                                    ui.label(progress_string(
-                                        (recipe.time_required - refinery.remaining_processing_time)
-                                            / recipe.time_required,
-                                    ));
-                                });
-                            }
-
-                            ui.heading("Refine Raw Ores:");
-
-                            for recipe in &refinery.recipes {
-                                ui.group(|ui| {
-                                    ui.vertical(|ui| {
-                                        ui.horizontal(|ui| {
-                                            ui.label(format!("{:?}", recipe.item_created));
-                                            ui.label(format!(
-                                                "Requires: {:?}",
-                                                recipe.items_required
-                                            ));
-                                            if inventory.has_items(recipe.items_required.clone()) {
-                                                ui.label("👍");
-                                            }
-                                        });
-
-                                        ui.horizontal(|ui| {
-                                            ui.label(format!(
-                                                "Time Required: {:.1} sec",
-                                                recipe.time_required
-                                            ));
-
-                                            if ui.button("Smelt").clicked() {
-                                                smelt_events.send(SmeltEvent(recipe.clone()));
-                                            }
-                                        })
-                                    });
-                                });
-                            }
-                        });
+                    BuildingType::Factory | BuildingType::Refinery => {
+                        ui.label("Select the module to manage its production queue.");
                     }
                     BuildingType::Storage => {
                         let inventory = inventory_query.single();
@@ -6,15 +6,30 @@ use bevy_egui::{
 };
 
 use crate::{
-    battery::components::Battery, health::components::Health, player::components::Player,
+    asteroid::components::AsteroidMaterial,
+    battery::components::Battery,
+    collectible::components::TractorBeam,
+    health::components::{Health, Shield},
+    player::components::Player,
     ui::helpers::progress_string,
+    PIXELS_PER_METER,
 };
 
 pub fn ui_ship_information(
-    player_query: Query<(&Player, &Health, &Battery, &LinearVelocity), With<Player>>,
+    mut player_query: Query<
+        (
+            &Player,
+            &Health,
+            &Shield,
+            &Battery,
+            &LinearVelocity,
+            &mut TractorBeam,
+        ),
+        With<Player>,
+    >,
     mut ctx: EguiContexts,
 ) {
-    let (player, health, battery, velocity) = player_query.single();
+    let (player, health, shield, battery, velocity, mut tractor_beam) = player_query.single_mut();
 
     Window::new("Ship Information")
         .anchor(Align2::LEFT_TOP, Vec2 { x: 0.0, y: 0.0 })
@@ -30,6 +45,11 @@ pub fn ui_ship_information(
                         ui.label(progress_string(health_percent));
                     });
 
+                    ui.group(|ui| {
+                        ui.label(format!("Shield: {:.2}%", shield.current_percent() * 100.0));
+                        ui.label(progress_string(shield.current_percent()));
+                    });
+
                     ui.group(|ui| {
                         ui.label(format!("Battery: {:.2}KWh", battery.current()));
                         let battery_percent = battery.current() / 1000.0;
@@ -49,6 +69,25 @@ pub fn ui_ship_information(
                     // TODO: procedure to convert direction_radians to cardinal directions (nice to have)
                     ui.label(format!("Direction: {:.2}", direction_radians));
                 });
+
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "Tractor Beam: {:.0}m",
+                        tractor_beam.range() / PIXELS_PER_METER as f32
+                    ));
+
+                    for material in [
+                        AsteroidMaterial::Rock,
+                        AsteroidMaterial::Iron,
+                        AsteroidMaterial::Silver,
+                        AsteroidMaterial::Gold,
+                    ] {
+                        let mut pulls = tractor_beam.pulls(material);
+                        if ui.checkbox(&mut pulls, material.to_string()).changed() {
+                            tractor_beam.set_pulls(material, pulls);
+                        }
+                    }
+                });
             });
         });
 }
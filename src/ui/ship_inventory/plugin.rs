@@ -3,12 +3,13 @@ use bevy::{
     prelude::{App, Plugin},
 };
 
-use super::systems::ui_ship_inventory;
+use super::{resources::SelectedCargoSlot, systems::ui_ship_inventory};
 
 pub struct ShipInventoryPlugin;
 
 impl Plugin for ShipInventoryPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, ui_ship_inventory);
+        app.init_resource::<SelectedCargoSlot>()
+            .add_systems(Update, ui_ship_inventory);
     }
 }
@@ -1 +1,5 @@
+use bevy::prelude::Resource;
 
+/// Cargo slot picked in the ship inventory window, waiting for a second slot to move it to.
+#[derive(Resource, Default)]
+pub struct SelectedCargoSlot(pub Option<usize>);
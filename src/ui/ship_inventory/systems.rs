@@ -1,17 +1,29 @@
-use bevy::ecs::system::Query;
+use bevy::{
+    ecs::system::{Query, Res, ResMut},
+    log::warn,
+};
 use bevy_egui::{
-    egui::{Align2, Window},
+    egui::{Align2, Grid, Window},
     EguiContexts,
 };
 
 use crate::{
-    inventory::components::Inventory, player::components::Player, ui::helpers::progress_string,
+    inventory::components::{Inventory, SlotSortOrder},
+    items::resources::ItemRegistry,
+    player::components::Player,
+    ui::helpers::progress_string,
 };
 
+use super::resources::SelectedCargoSlot;
+
+const SLOTS_PER_ROW: usize = 4;
+
 pub fn ui_ship_inventory(
     // world: &mut World,
     mut ctx: EguiContexts,
     mut inventory_query: Query<(&Player, &mut Inventory)>,
+    item_registry: Res<ItemRegistry>,
+    mut selected_slot: ResMut<SelectedCargoSlot>,
 ) {
     Window::new("Ship Inventory")
         .auto_sized()
@@ -22,17 +34,84 @@ pub fn ui_ship_inventory(
             bevy_egui::egui::Vec2 { x: 0.0, y: 0.0 },
         )
         .show(ctx.ctx_mut(), |ui| {
-            let (_, inventory) = inventory_query.single_mut();
+            let (_, mut inventory) = inventory_query.single_mut();
 
-            let inventory_capacity_percent =
-                (1.0 - inventory.remaining_capacity().0 / inventory.capacity.maximum.0) * 100.0;
+            let inventory_capacity_percent = (1.0
+                - inventory.remaining_capacity(&item_registry).0 / inventory.capacity.maximum.0)
+                * 100.0;
             ui.label(format!("Capacity: {:.2}%", inventory_capacity_percent));
             ui.label(progress_string(inventory_capacity_percent / 100.0));
 
-            ui.group(|ui| {
-                for item in inventory.items.clone() {
-                    ui.label(format!("{:?}", item));
+            // Work on a copy so the inventory is only marked as changed when the slots actually are.
+            let Some(mut slots) = inventory.slots.clone() else {
+                ui.group(|ui| {
+                    for item in inventory.items.clone() {
+                        ui.label(item_registry.describe(&item));
+                    }
+                });
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label("Sort:");
+                for (label, order) in [
+                    ("Category", SlotSortOrder::Category),
+                    ("Value", SlotSortOrder::Value),
+                    ("Weight", SlotSortOrder::Weight),
+                ] {
+                    if ui.button(label).clicked() {
+                        if let Err(overflow) = slots.sort(order, &item_registry) {
+                            warn!("Can't sort the cargo slots, {:?} wouldn't fit", overflow);
+                        }
+                    }
+                }
+            });
+
+            let split_half = ui.input(|input| input.modifiers.shift);
+
+            Grid::new("cargo_slots").show(ui, |ui| {
+                for index in 0..slots.slots.len() {
+                    let slot = &slots.slots[index];
+                    let mut text = match &slot.stack {
+                        Some(stack) => item_registry.describe(stack),
+                        None => "Empty".to_string(),
+                    };
+                    if slot.locked {
+                        text = format!("[Locked] {}", text);
+                    }
+
+                    let response = ui.selectable_label(selected_slot.0 == Some(index), text);
+
+                    if response.secondary_clicked() {
+                        slots.toggle_lock(index);
+                    } else if response.clicked() {
+                        match selected_slot.0.take() {
+                            None => selected_slot.0 = Some(index),
+                            Some(from) => {
+                                if !slots.merge(from, index, &item_registry) {
+                                    if let Some(stack) = slots.slots[from].stack.clone() {
+                                        let amount = if split_half {
+                                            stack.amount().with_units(stack.amount().units() / 2.0)
+                                        } else {
+                                            stack.amount()
+                                        };
+                                        slots.split(from, index, amount);
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if (index + 1) % SLOTS_PER_ROW == 0 {
+                        ui.end_row();
+                    }
                 }
-            })
+            });
+
+            ui.label("Click two slots to move or merge, shift to split, right click to lock.");
+
+            if inventory.slots.as_ref() != Some(&slots) {
+                inventory.slots = Some(slots);
+            }
         });
 }
@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use bevy_egui::{
-    egui::{Align2, Vec2, Window},
+    egui::{Align2, Button, Vec2, Window},
     EguiContexts,
 };
 
 use crate::{
+    items::resources::ItemRegistry,
     player::components::Player,
+    research::{components::Unlock, resources::ResearchUnlocks},
     upgrades::{components::UpgradesComponent, events::UpgradeEvent},
 };
 
@@ -14,6 +16,8 @@ pub fn ui_space_station_menu(
     // cc_res: Res<ContextClues>,
     player_query: Query<(&Player, &UpgradesComponent)>,
     mut upgrade_events: EventWriter<UpgradeEvent>,
+    item_registry: Res<ItemRegistry>,
+    unlocks: Res<ResearchUnlocks>,
 ) {
     Window::new("Space Station Information")
         .anchor(Align2::RIGHT_BOTTOM, Vec2 { x: 0.0, y: 0.0 })
@@ -28,9 +32,14 @@ pub fn ui_space_station_menu(
                         ui.horizontal(|ui| {
                             ui.vertical(|ui| {
                                 ui.label(format!("{:?}", upgrade));
-                                if ui.button("Upgrade").clicked() {
+                                let researched =
+                                    unlocks.is_unlocked(&Unlock::Upgrade(upgrade.next()));
+                                if ui.add_enabled(researched, Button::new("Upgrade")).clicked() {
                                     upgrade_events.send(UpgradeEvent(*upgrade));
                                 }
+                                if !researched {
+                                    ui.label("Requires research");
+                                }
                             });
 
                             ui.vertical(|ui| {
@@ -39,7 +48,7 @@ pub fn ui_space_station_menu(
                                     for requirement in upgrade.requirements().unwrap().requirements
                                     {
                                         // TODO: This seems unnecessairly convoluted..
-                                        ui.label(format!("{:?}", requirement));
+                                        ui.label(item_registry.describe(&requirement));
                                     }
                                 }
                             });
@@ -0,0 +1,11 @@
+use bevy::app::{App, Plugin, Update};
+
+use super::systems::ui_wave_warning;
+
+pub struct WaveWarningPlugin;
+
+impl Plugin for WaveWarningPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, ui_wave_warning);
+    }
+}
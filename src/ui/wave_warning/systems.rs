@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{Align2, Color32, RichText, Vec2, Window},
+    EguiContexts,
+};
+
+use crate::waves::{
+    components::WaveEnemy,
+    resources::{WaveDirector, WavePhase},
+};
+
+/// Warns of an incoming wave, and shows how many enemies of the current one are left.
+pub fn ui_wave_warning(
+    mut ctx: EguiContexts,
+    director: Res<WaveDirector>,
+    wave_enemies: Query<(), With<WaveEnemy>>,
+) {
+    let text = if let Some((wave, remaining)) = director.warning() {
+        RichText::new(format!(
+            "Wave {} incoming in {:.0}s!",
+            wave,
+            remaining.ceil()
+        ))
+        .color(Color32::RED)
+        .heading()
+    } else if let WavePhase::Active { .. } = director.phase {
+        RichText::new(format!(
+            "Wave {}: {} enemies left",
+            director.wave,
+            wave_enemies.iter().count()
+        ))
+    } else {
+        return;
+    };
+
+    Window::new("Wave")
+        .anchor(Align2::CENTER_TOP, Vec2 { x: 0.0, y: 0.0 })
+        .title_bar(false)
+        .resizable(false)
+        .show(ctx.ctx_mut(), |ui| {
+            ui.label(text);
+        });
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::Component;
+use serde::Deserialize;
 use strum::IntoEnumIterator;
 use strum_macros::{EnumIter, FromRepr};
 
@@ -6,20 +7,16 @@ use crate::inventory::components::{Inventory, InventoryItem};
 use crate::items::Amount;
 use crate::player::components::Player;
 
+/// Extra weight capacity in kilograms added by each cargo bay upgrade.
+pub const CARGO_BAY_CAPACITY_PER_LEVEL: f32 = 50.0;
+/// Extra cargo slots added by each cargo bay upgrade.
+pub const CARGO_BAY_SLOTS_PER_LEVEL: usize = 2;
+
 #[derive(Component, Default)]
 pub struct UpgradesComponent {
     pub upgrades: Vec<UpgradeType>,
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Hash)]
-pub enum UpgradeComponent {
-    #[default]
-    Cog,
-    IronPlate,
-    SilverConduit,
-    GoldLeaf,
-}
-
 impl UpgradesComponent {
     pub fn new() -> Self {
         let mut upgrades = vec![];
@@ -36,6 +33,7 @@ impl UpgradesComponent {
         &mut self,
         upgrade_type: UpgradeType,
         player: &mut Player,
+        station_inventory: &mut Inventory,
         ship_inventory: &mut Inventory,
     ) {
         if let Some(to_upgrade) = self
@@ -45,23 +43,32 @@ impl UpgradesComponent {
         {
             let upgrade_requirements = to_upgrade.next().requirements().unwrap().requirements;
 
-            if ship_inventory.has_items(upgrade_requirements.clone()) {
+            if station_inventory.has_items(upgrade_requirements.clone()) {
                 *to_upgrade = match to_upgrade {
                     UpgradeType::None => UpgradeType::None,
                     UpgradeType::Health(level) => {
                         let next = level.next().unwrap_or(UpgradeLevel::MaxLevel);
                         // TODO: Fix this after health refactor. Upgrades should be handled with events.
                         // player.health.set_upgrade_level(next);
-                        ship_inventory.remove_all_from_inventory(upgrade_requirements.clone());
+                        station_inventory.remove_all_from_inventory(upgrade_requirements.clone());
                         UpgradeType::Health(next)
                     }
                     UpgradeType::ShipCargoBay(level) => {
                         let next = level.next().unwrap_or(UpgradeLevel::MaxLevel);
-                        // TODO: Fix this after battery refactor. Upgrades should be handled with events.
-                        // player.battery.set_upgrade_level(next);
-                        ship_inventory.remove_all_from_inventory(upgrade_requirements.clone());
+                        station_inventory.remove_all_from_inventory(upgrade_requirements.clone());
+
+                        ship_inventory.capacity.maximum += CARGO_BAY_CAPACITY_PER_LEVEL;
+                        if let Some(slots) = ship_inventory.slots.as_mut() {
+                            slots.add_slots(CARGO_BAY_SLOTS_PER_LEVEL);
+                        }
+
                         UpgradeType::ShipCargoBay(next)
                     }
+                    UpgradeType::TractorBeam(level) => {
+                        let next = level.next().unwrap_or(UpgradeLevel::MaxLevel);
+                        station_inventory.remove_all_from_inventory(upgrade_requirements.clone());
+                        UpgradeType::TractorBeam(next)
+                    }
                 }
             } else {
                 println!("DON'T HAVE MATERIALS REQUIRED FOR UPGRADE!");
@@ -72,12 +79,13 @@ impl UpgradesComponent {
     }
 }
 
-#[derive(Default, EnumIter, Clone, Copy, Debug, PartialEq)]
+#[derive(Default, EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
 pub enum UpgradeType {
     #[default]
     None,
     Health(UpgradeLevel),
     ShipCargoBay(UpgradeLevel),
+    TractorBeam(UpgradeLevel),
 }
 
 impl ToString for UpgradeType {
@@ -85,6 +93,7 @@ impl ToString for UpgradeType {
         match self {
             UpgradeType::Health(_) => "Health",
             UpgradeType::ShipCargoBay(_) => "Ship Cargo Bay",
+            UpgradeType::TractorBeam(_) => "Tractor Beam",
             UpgradeType::None => "NONE UPGRADE.",
         }
         .to_string()
@@ -103,38 +112,29 @@ impl UpgradeType {
                 requirements = match level {
                     UpgradeLevel::Level0 => vec![],
                     UpgradeLevel::Level1 => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(1)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(2)),
+                        InventoryItem::new("cog", Amount::Quantity(1)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(2)),
                     ],
                     UpgradeLevel::Level2 => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(2)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(3)),
+                        InventoryItem::new("cog", Amount::Quantity(2)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(3)),
                     ],
                     UpgradeLevel::Level3 => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(1)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(2)),
-                        InventoryItem::Component(
-                            UpgradeComponent::SilverConduit,
-                            Amount::Quantity(1),
-                        ),
+                        InventoryItem::new("cog", Amount::Quantity(1)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(2)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(1)),
                     ],
                     UpgradeLevel::Level4 => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(3)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(5)),
-                        InventoryItem::Component(
-                            UpgradeComponent::SilverConduit,
-                            Amount::Quantity(3),
-                        ),
-                        InventoryItem::Component(UpgradeComponent::GoldLeaf, Amount::Quantity(1)),
+                        InventoryItem::new("cog", Amount::Quantity(3)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(5)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(3)),
+                        InventoryItem::new("gold_leaf", Amount::Quantity(1)),
                     ],
                     UpgradeLevel::MaxLevel => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(10)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(5)),
-                        InventoryItem::Component(
-                            UpgradeComponent::SilverConduit,
-                            Amount::Quantity(5),
-                        ),
-                        InventoryItem::Component(UpgradeComponent::GoldLeaf, Amount::Quantity(3)),
+                        InventoryItem::new("cog", Amount::Quantity(10)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(5)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(5)),
+                        InventoryItem::new("gold_leaf", Amount::Quantity(3)),
                     ],
                 }
             }
@@ -142,13 +142,53 @@ impl UpgradeType {
                 requirements = match level {
                     UpgradeLevel::Level0 => vec![],
                     UpgradeLevel::Level1 => vec![
-                        InventoryItem::Component(UpgradeComponent::Cog, Amount::Quantity(2)),
-                        InventoryItem::Component(UpgradeComponent::IronPlate, Amount::Quantity(3)),
+                        InventoryItem::new("cog", Amount::Quantity(2)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(3)),
+                    ],
+                    UpgradeLevel::Level2 => vec![
+                        InventoryItem::new("cog", Amount::Quantity(3)),
+                        InventoryItem::new("iron_plate", Amount::Quantity(5)),
+                    ],
+                    UpgradeLevel::Level3 => vec![
+                        InventoryItem::new("iron_plate", Amount::Quantity(6)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(2)),
+                    ],
+                    UpgradeLevel::Level4 => vec![
+                        InventoryItem::new("iron_plate", Amount::Quantity(8)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(3)),
+                        InventoryItem::new("circuit", Amount::Quantity(1)),
+                    ],
+                    UpgradeLevel::MaxLevel => vec![
+                        InventoryItem::new("iron_plate", Amount::Quantity(10)),
+                        InventoryItem::new("gold_leaf", Amount::Quantity(2)),
+                        InventoryItem::new("circuit", Amount::Quantity(2)),
+                    ],
+                }
+            }
+            UpgradeType::TractorBeam(level) => {
+                requirements = match level {
+                    UpgradeLevel::Level0 => vec![],
+                    UpgradeLevel::Level1 => vec![
+                        InventoryItem::new("iron_plate", Amount::Quantity(2)),
+                        InventoryItem::new("cog", Amount::Quantity(1)),
+                    ],
+                    UpgradeLevel::Level2 => vec![
+                        InventoryItem::new("iron_plate", Amount::Quantity(3)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(1)),
+                    ],
+                    UpgradeLevel::Level3 => vec![
+                        InventoryItem::new("cog", Amount::Quantity(2)),
+                        InventoryItem::new("silver_conduit", Amount::Quantity(2)),
+                    ],
+                    UpgradeLevel::Level4 => vec![
+                        InventoryItem::new("silver_conduit", Amount::Quantity(3)),
+                        InventoryItem::new("circuit", Amount::Quantity(1)),
+                    ],
+                    UpgradeLevel::MaxLevel => vec![
+                        InventoryItem::new("silver_conduit", Amount::Quantity(5)),
+                        InventoryItem::new("gold_leaf", Amount::Quantity(2)),
+                        InventoryItem::new("circuit", Amount::Quantity(2)),
                     ],
-                    UpgradeLevel::Level2 => todo!(),
-                    UpgradeLevel::Level3 => todo!(),
-                    UpgradeLevel::Level4 => todo!(),
-                    UpgradeLevel::MaxLevel => todo!(),
                 }
             }
         }
@@ -173,6 +213,13 @@ impl UpgradeType {
                     *self
                 }
             }
+            UpgradeType::TractorBeam(level) => {
+                if let Some(next_level) = level.next() {
+                    UpgradeType::TractorBeam(next_level)
+                } else {
+                    *self
+                }
+            }
         }
     }
 }
@@ -187,7 +234,7 @@ pub trait Upgradeable {
     fn upgrade_effect(&self) -> f32;
 }
 
-#[derive(FromRepr, EnumIter, Debug, Clone, Copy, Default, PartialEq)]
+#[derive(FromRepr, EnumIter, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
 #[repr(u8)]
 pub enum UpgradeLevel {
     #[default]
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::ai::components::EnemyArchetype;
+
+/// Seconds a wave is expected to take to clear, quicker clears count as a good performance.
+const PAR_CLEAR_TIME: f32 = 90.0;
+const MIN_PERFORMANCE: f32 = 0.5;
+const MAX_PERFORMANCE: f32 = 2.0;
+
+/// Marks enemies spawned as part of a wave, the wave is cleared once none of them are left.
+#[derive(Component, Debug)]
+pub struct WaveEnemy {
+    pub wave: u32,
+}
+
+/// Budget a wave spends on enemies. Grows with the wave number and the time played,
+/// and slowly with the value of the station, which makes it a juicier target.
+/// Scaled by how well the player did on the previous waves.
+pub fn wave_budget(wave: u32, minutes_played: f32, station_value: f32, performance: f32) -> f32 {
+    let base = 2.0 + wave as f32 * 1.5;
+    let time = minutes_played * 0.2;
+    let wealth = (1.0 + station_value.max(0.0) / 1000.0).ln() * 2.0;
+
+    (base + time + wealth) * performance
+}
+
+/// Picks random enemies until the budget runs out, keeping to each archetype's first wave and
+/// per-wave limit. A wave always brings at least one enemy, even if it can't afford it.
+pub fn compose_wave<'a>(
+    budget: f32,
+    wave: u32,
+    archetypes: &[&'a EnemyArchetype],
+    rng: &mut impl Rng,
+) -> Vec<&'a EnemyArchetype> {
+    let eligible: Vec<&EnemyArchetype> = archetypes
+        .iter()
+        .copied()
+        .filter(|archetype| archetype.min_wave <= wave && archetype.cost > 0.0)
+        .collect();
+
+    let mut picks: Vec<&EnemyArchetype> = Vec::new();
+    let mut remaining = budget;
+
+    loop {
+        let affordable: Vec<&EnemyArchetype> = eligible
+            .iter()
+            .copied()
+            .filter(|archetype| archetype.cost <= remaining)
+            .filter(|archetype| {
+                let picked = picks.iter().filter(|pick| pick.id == archetype.id).count();
+                archetype
+                    .max_per_wave
+                    .into_iter()
+                    .all(|max| picked < max as usize)
+            })
+            .collect();
+
+        let Some(pick) = affordable.choose(rng) else {
+            break;
+        };
+
+        remaining -= pick.cost;
+        picks.push(*pick);
+    }
+
+    if picks.is_empty() {
+        if let Some(cheapest) = eligible
+            .iter()
+            .copied()
+            .min_by(|a, b| a.cost.total_cmp(&b.cost))
+        {
+            picks.push(cheapest);
+        }
+    }
+
+    picks
+}
+
+/// Updates the performance rating after a cleared wave. Quick clears with the ship in good shape
+/// push it up, slow and costly ones ease off.
+pub fn rate_performance(performance: f32, clear_time: f32, health_fraction: f32) -> f32 {
+    let speed = (PAR_CLEAR_TIME / clear_time.max(1.0)).clamp(0.5, 2.0);
+    let rating = speed * (0.5 + health_fraction.clamp(0.0, 1.0));
+
+    (performance * 0.7 + rating * 0.3).clamp(MIN_PERFORMANCE, MAX_PERFORMANCE)
+}
+
+#[cfg(test)]
+fn test_archetypes() -> Vec<EnemyArchetype> {
+    use crate::ai::components::EnemyArchetypes;
+
+    let archetypes: EnemyArchetypes = ron::de::from_str(
+        r#"(archetypes: [
+            (id: "grunt", name: "Grunt", behaviour: Drone, radius: 5.0, color: (1.0, 0.0, 0.0),
+                health: 10.0, thrust: 10.0, max_speed: 100.0, cost: 1.0),
+            (id: "tank", name: "Tank", behaviour: Drone, radius: 5.0, color: (1.0, 0.0, 0.0),
+                health: 10.0, thrust: 10.0, max_speed: 100.0, cost: 3.0, min_wave: 3),
+            (id: "boss", name: "Boss", behaviour: Drone, radius: 5.0, color: (1.0, 0.0, 0.0),
+                health: 10.0, thrust: 10.0, max_speed: 100.0, cost: 5.0, max_per_wave: Some(1)),
+        ])"#,
+    )
+    .unwrap();
+
+    archetypes.archetypes
+}
+
+#[test]
+fn test_compose_wave_keeps_to_budget_and_rules() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let archetypes = test_archetypes();
+    let archetypes: Vec<&EnemyArchetype> = archetypes.iter().collect();
+
+    for seed in 0..100 {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let picks = compose_wave(12.0, 1, &archetypes, &mut rng);
+
+        let spent: f32 = picks.iter().map(|pick| pick.cost).sum();
+        assert!(spent <= 12.0);
+        // Grunts fill up whatever is left, so nothing of the budget goes to waste.
+        assert!(spent > 11.0);
+        assert!(picks.iter().all(|pick| pick.id != "tank"));
+        assert!(picks.iter().filter(|pick| pick.id == "boss").count() <= 1);
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let picks = compose_wave(12.0, 3, &archetypes, &mut rng);
+    assert!(picks.iter().map(|pick| pick.cost).sum::<f32>() <= 12.0);
+
+    // Too small a budget still brings the cheapest enemy.
+    let picks = compose_wave(0.5, 1, &archetypes, &mut rng);
+    assert_eq!(
+        picks
+            .iter()
+            .map(|pick| pick.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["grunt"]
+    );
+}
+
+#[test]
+fn test_wave_budget_scales_up() {
+    let first = wave_budget(1, 0.0, 0.0, 1.0);
+    assert_eq!(first, 3.5);
+
+    assert!(wave_budget(5, 0.0, 0.0, 1.0) > first);
+    assert!(wave_budget(1, 30.0, 0.0, 1.0) > first);
+    assert!(wave_budget(1, 0.0, 10_000.0, 1.0) > first);
+    assert_eq!(wave_budget(1, 0.0, 0.0, 2.0), 2.0 * first);
+}
+
+#[test]
+fn test_rate_performance() {
+    let quick_and_clean = rate_performance(1.0, 30.0, 1.0);
+    assert!(quick_and_clean > 1.0);
+
+    let slow_and_battered = rate_performance(1.0, 300.0, 0.1);
+    assert!(slow_and_battered < 1.0);
+
+    // Repeated bad waves bottom out instead of making the game trivial.
+    let mut performance = 1.0;
+    for _ in 0..50 {
+        performance = rate_performance(performance, 600.0, 0.0);
+    }
+    assert_eq!(performance, 0.5);
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::Event;
+
+/// Spawns the next wave's enemies, composed from the wave's budget.
+#[derive(Event)]
+pub struct SpawnWaveEvent {
+    pub wave: u32,
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+use super::{
+    events::SpawnWaveEvent,
+    resources::WaveDirector,
+    systems::{on_spawn_wave, update_wave_director},
+};
+
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpawnWaveEvent>()
+            .init_resource::<WaveDirector>()
+            .add_systems(Update, update_wave_director)
+            .add_observer(on_spawn_wave);
+    }
+}
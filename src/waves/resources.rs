@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+/// Seconds before the first wave's warning goes up.
+pub const FIRST_WAVE_DELAY: f32 = 120.0;
+/// Seconds of breathing room between a cleared wave and the next warning.
+pub const REST_TIME: f32 = 60.0;
+/// Seconds the warning is up before a wave arrives.
+pub const WARNING_TIME: f32 = 15.0;
+
+#[derive(Debug)]
+pub enum WavePhase {
+    /// Breathing room before the next wave is announced.
+    Resting(Timer),
+    /// The next wave is announced and on its way.
+    Warning(Timer),
+    /// The current wave is being fought, since the given time played.
+    Active { started: f32 },
+}
+
+/// Schedules enemy waves and keeps track of how the player handled the previous ones.
+#[derive(Resource, Debug)]
+pub struct WaveDirector {
+    /// The last wave that arrived, 0 before the first one.
+    pub wave: u32,
+    pub phase: WavePhase,
+    /// Seconds played so far.
+    pub played: f32,
+    /// How well the player handled the previous waves, scaling the budget of the next ones.
+    pub performance: f32,
+}
+
+impl Default for WaveDirector {
+    fn default() -> Self {
+        Self {
+            wave: 0,
+            phase: WavePhase::Resting(Timer::from_seconds(FIRST_WAVE_DELAY, TimerMode::Once)),
+            played: 0.0,
+            performance: 1.0,
+        }
+    }
+}
+
+impl WaveDirector {
+    /// The announced wave and the seconds until it arrives, while a warning is up.
+    pub fn warning(&self) -> Option<(u32, f32)> {
+        match &self.phase {
+            WavePhase::Warning(timer) => Some((self.wave + 1, timer.remaining_secs())),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    ai::{components::EnemyArchetype, resources::EnemyArchetypeRegistry, systems::spawn_enemy},
+    camera::components::GameCamera,
+    health::components::Health,
+    inventory::components::Inventory,
+    items::resources::ItemRegistry,
+    player::components::Player,
+    space_station::components::SpaceStation,
+    world_seed::resources::{RngStream, SeededRng},
+};
+
+use super::{
+    components::{compose_wave, rate_performance, wave_budget, WaveEnemy},
+    events::SpawnWaveEvent,
+    resources::{WaveDirector, WavePhase, REST_TIME, WARNING_TIME},
+};
+
+/// Distance beyond the edge of the view waves spawn at.
+const SPAWN_MARGIN: f32 = 300.0;
+/// Spawn distance used when there's no camera to measure the view with.
+const DEFAULT_SPAWN_DISTANCE: f32 = 1500.0;
+/// Enemies of a wave spread out this far around their spawn point.
+const SPAWN_SPREAD: f32 = 200.0;
+/// Waves with more enemies than this come from two directions at once.
+const SPLIT_WAVE_SIZE: usize = 6;
+
+/// Moves the director from resting to warning to the wave itself, and back to resting once
+/// the wave is cleared. F1 skips ahead to the next phase.
+pub fn update_wave_director(
+    mut commands: Commands,
+    mut director: ResMut<WaveDirector>,
+    wave_enemies: Query<(), With<WaveEnemy>>,
+    player_query: Query<&Health, With<Player>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    // Reborrow so the phase and the performance can be borrowed separately.
+    let director = &mut *director;
+
+    director.played += time.delta_secs();
+    let skip = keys.just_pressed(KeyCode::F1);
+    let played = director.played;
+    let wave = director.wave;
+
+    let next_phase = match &mut director.phase {
+        WavePhase::Resting(timer) => {
+            timer.tick(time.delta());
+            (timer.finished() || skip).then(|| {
+                info!("Wave {} incoming.", wave + 1);
+                WavePhase::Warning(Timer::from_seconds(WARNING_TIME, TimerMode::Once))
+            })
+        }
+        WavePhase::Warning(timer) => {
+            timer.tick(time.delta());
+            (timer.finished() || skip).then(|| {
+                commands.trigger(SpawnWaveEvent { wave: wave + 1 });
+                WavePhase::Active { started: played }
+            })
+        }
+        WavePhase::Active { started } => wave_enemies.is_empty().then(|| {
+            let health = player_query
+                .get_single()
+                .map_or(1.0, |health| health.current_percent());
+            let clear_time = played - *started;
+            info!("Wave {} cleared in {:.0}s.", wave, clear_time);
+
+            director.performance = rate_performance(director.performance, clear_time, health);
+            WavePhase::Resting(Timer::from_seconds(REST_TIME, TimerMode::Once))
+        }),
+    };
+
+    if let Some(next_phase) = next_phase {
+        if matches!(next_phase, WavePhase::Active { .. }) {
+            director.wave += 1;
+        }
+        director.phase = next_phase;
+    }
+}
+
+/// Spends the wave's budget on enemies and spawns them just out of view,
+/// closing in on either the player or the station.
+#[allow(clippy::too_many_arguments)]
+pub fn on_spawn_wave(
+    trigger: Trigger<SpawnWaveEvent>,
+    mut commands: Commands,
+    director: Res<WaveDirector>,
+    registry: Res<EnemyArchetypeRegistry>,
+    item_registry: Res<ItemRegistry>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    station_query: Query<(&GlobalTransform, &Inventory), With<SpaceStation>>,
+    camera_query: Query<&OrthographicProjection, With<GameCamera>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut seeded_rng: ResMut<SeededRng>,
+) {
+    let wave = trigger.event().wave;
+    let rng = seeded_rng.stream(RngStream::Waves);
+
+    let station = station_query.get_single().ok();
+    let station_value: f32 = station.map_or(0.0, |(_, inventory)| {
+        inventory
+            .items
+            .iter()
+            .map(|item| item_registry.value_of(item))
+            .sum()
+    });
+
+    let budget = wave_budget(
+        wave,
+        director.played / 60.0,
+        station_value,
+        director.performance,
+    );
+
+    let mut archetypes: Vec<&EnemyArchetype> = registry.spawnable().collect();
+    archetypes.sort_by(|a, b| a.id.cmp(&b.id));
+    let picks = compose_wave(budget, wave, &archetypes, rng);
+
+    let player_position = player_query
+        .get_single()
+        .map(|gt| gt.translation().truncate());
+    let station_position = station.map(|(gt, _)| gt.translation().truncate());
+
+    let anchor = match (player_position, station_position) {
+        (Ok(player), Some(station)) => {
+            if rng.gen_bool(0.5) {
+                player
+            } else {
+                station
+            }
+        }
+        (Ok(player), None) => player,
+        (Err(_), Some(station)) => station,
+        (Err(_), None) => Vec2::ZERO,
+    };
+
+    let distance = camera_query
+        .get_single()
+        .map_or(DEFAULT_SPAWN_DISTANCE, |projection| {
+            projection.area.half_size().length() + SPAWN_MARGIN
+        });
+
+    let groups = if picks.len() > SPLIT_WAVE_SIZE { 2 } else { 1 };
+    let directions: Vec<Vec2> = (0..groups)
+        .map(|_| Vec2::from_angle(rng.gen_range(0.0..TAU)))
+        .collect();
+
+    info!(
+        "Wave {} arrives with {} enemies for a budget of {:.1}.",
+        wave,
+        picks.len(),
+        budget
+    );
+
+    for (i, archetype) in picks.into_iter().enumerate() {
+        let spread = Vec2::new(
+            rng.gen_range(-SPAWN_SPREAD..SPAWN_SPREAD),
+            rng.gen_range(-SPAWN_SPREAD..SPAWN_SPREAD),
+        );
+        let position = anchor + directions[i % groups] * distance + spread;
+
+        let enemy = spawn_enemy(
+            &mut commands,
+            archetype,
+            position,
+            &mut meshes,
+            &mut materials,
+        );
+        commands.entity(enemy).insert(WaveEnemy { wave });
+    }
+}
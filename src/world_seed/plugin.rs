@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use super::{
+    resources::{SeededRng, WorldSeed},
+    systems::{log_world_seed, reseed_rng},
+};
+
+pub struct WorldSeedPlugin;
+
+impl Plugin for WorldSeedPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = WorldSeed::from_env();
+
+        app.insert_resource(seed)
+            .insert_resource(SeededRng::new(seed))
+            .add_systems(Startup, log_world_seed)
+            .add_systems(PreUpdate, reseed_rng);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    env,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{prelude::*, utils::HashMap};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Environment variable that fixes the world seed, ie. to reproduce a bug report.
+pub const WORLD_SEED_ENV: &str = "GEOMETRY_WARS_SEED";
+
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// The seed every random stream of the world is derived from.
+/// The same seed gives the same asteroid field.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorldSeed(pub u64);
+
+impl WorldSeed {
+    /// Picks the seed from the command line (`--seed <n>` or `--daily`),
+    /// then the environment, falling back to a random seed.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        if args.iter().any(|arg| arg == "--daily") {
+            return Self::daily();
+        }
+
+        let from_args = args
+            .windows(2)
+            .find(|pair| pair[0] == "--seed")
+            .and_then(|pair| pair[1].parse().ok());
+        let from_env = env::var(WORLD_SEED_ENV)
+            .ok()
+            .and_then(|seed| seed.parse().ok());
+
+        from_args
+            .or(from_env)
+            .map(Self)
+            .unwrap_or_else(Self::random)
+    }
+
+    /// The seed shared by every player on the current (UTC) day.
+    pub fn daily() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_secs() / SECONDS_PER_DAY)
+            .unwrap_or_default();
+
+        Self(days)
+    }
+
+    pub fn random() -> Self {
+        Self(rand::random())
+    }
+}
+
+/// Independent random streams, one per subsystem, so that drawing more numbers
+/// in one subsystem doesn't change what another one generates.
+/// New streams go at the end, so the existing ones keep their numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RngStream {
+    AsteroidShapes,
+    AsteroidComposition,
+    AsteroidFields,
+    SplitRatios,
+    Byproducts,
+    WeaponSpread,
+    Waves,
+}
+
+/// Seeded random number generators for each RngStream, derived from the WorldSeed.
+#[derive(Resource, Debug, Clone)]
+pub struct SeededRng {
+    seed: WorldSeed,
+    streams: HashMap<RngStream, ChaCha8Rng>,
+}
+
+impl SeededRng {
+    pub fn new(seed: WorldSeed) -> Self {
+        Self {
+            seed,
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn seed(&self) -> WorldSeed {
+        self.seed
+    }
+
+    /// Returns the generator of a stream, starting it from the world seed on first use.
+    pub fn stream(&mut self, stream: RngStream) -> &mut ChaCha8Rng {
+        let seed = self.seed.0;
+
+        self.streams.entry(stream).or_insert_with(|| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            rng.set_stream(stream as u64);
+            rng
+        })
+    }
+
+    /// Returns a fresh generator for one chunk of the world. It only depends on the seed,
+    /// the stream and the chunk, so a chunk looks the same no matter when it's generated.
+    pub fn for_chunk(&self, stream: RngStream, chunk: IVec2) -> ChaCha8Rng {
+        let chunk_seed = self.seed.0
+            ^ (chunk.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (chunk.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(chunk_seed);
+        rng.set_stream(stream as u64);
+        rng
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use super::resources::{SeededRng, WorldSeed};
+
+pub fn log_world_seed(seed: Res<WorldSeed>) {
+    info!("World seed: {}", seed.0);
+}
+
+/// Restarts every random stream when the world seed is changed, ie. for a new run.
+pub fn reseed_rng(seed: Res<WorldSeed>, mut rng: ResMut<SeededRng>) {
+    if seed.is_changed() && rng.seed() != *seed {
+        *rng = SeededRng::new(*seed);
+    }
+}